@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+use std::io::{Error as IOError, ErrorKind, Result as IOResult};
+use std::path::Path;
+
+use crate::CMD_LINE_OPTION_ERROR_NUM;
+
+/// Error message keys, in the same order of the error message indexes
+/// (i.g. ERROR_KEYS[MISSING_OPTION_ARGUMENT_ERROR_IDX] is "missing_option_argument")
+pub const ERROR_KEYS: [&str; CMD_LINE_OPTION_ERROR_NUM] = [
+    "option_already_exists",
+    "short_option_already_exists",
+    "long_option_already_exists",
+    "option_is_not_set",
+    "option_is_not_of_required_type",
+    "option_not_found",
+    "option_type_undefined",
+    "boolean_option_mismatch_value_set",
+    "integer_option_mismatch_value_set",
+    "fpoint_option_mismatch_value_set",
+    "missing_option_argument",
+    "mandatory_option_has_not_set",
+    "option_identifier_not_found",
+    "option_argument_already_assigned",
+];
+
+/// Warning message keys
+pub const DEPRECATED_OPTION_WARNING_KEY: &str = "deprecated_option";
+pub const DEPRECATED_OPTION_REPLACEMENT_WARNING_KEY: &str = "deprecated_option_replacement";
+
+/// Command error message keys
+pub const COMMAND_ALREADY_EXISTS_ERROR_KEY: &str = "command_already_exists";
+pub const COMMAND_NOT_FOUND_ERROR_KEY: &str = "command_not_found";
+pub const MISSING_COMMAND_ERROR_KEY: &str = "missing_command";
+pub const MISSING_EXTERNAL_COMMAND_ERROR_KEY: &str = "missing_external_command";
+pub const EXTERNAL_COMMAND_NOT_FOUND_ERROR_KEY: &str = "external_command_not_found";
+
+/// Command line text error message keys
+pub const UNTERMINATED_QUOTE_ERROR_KEY: &str = "unterminated_quote";
+pub const MISSING_ESCAPED_CHARACTER_ERROR_KEY: &str = "missing_escaped_character";
+
+// Messages in the English default language
+const ENGLISH_MESSAGES: [(&str, &str); CMD_LINE_OPTION_ERROR_NUM + 9] = [
+    ("option_already_exists", "option already exists"),
+    ("short_option_already_exists", "single character option already exists"),
+    ("long_option_already_exists", "long form option already exists"),
+    ("option_is_not_set", "is not set"),
+    ("option_is_not_of_required_type", "is not of the required type"),
+    ("option_not_found", "option not found"),
+    ("option_type_undefined", "Undefined command line option"),
+    ("boolean_option_mismatch_value_set", "cannot set a boolean value to this option type"),
+    ("integer_option_mismatch_value_set", "cannot set an integer value to this option type"),
+    ("fpoint_option_mismatch_value_set", "cannot set a floating point value to this option type"),
+    ("missing_option_argument", "missing option argument"),
+    ("mandatory_option_has_not_set", "mandatory option has not been set"),
+    ("option_identifier_not_found", "option identifier not found"),
+    ("option_argument_already_assigned", "option argument already assigned"),
+    ("deprecated_option", "option is deprecated"),
+    ("deprecated_option_replacement", "option is deprecated, use {value}"),
+    ("command_already_exists", "command already exists"),
+    ("command_not_found", "command not found"),
+    ("missing_command", "missing command"),
+    ("missing_external_command", "no external command"),
+    ("external_command_not_found", "external command not found"),
+    ("unterminated_quote", "unterminated quote at character {value}"),
+    ("missing_escaped_character", "missing escaped character at character {value}"),
+];
+
+/// Help text headings key prefix (i.g. heading_Options)
+pub const HEADING_KEY_PREFIX: &str = "heading_";
+
+// Environment variables used to detect the locale, in priority order
+const LOCALE_ENV_VARS: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+// Catalog file extension used by from_locale_dir
+const CATALOG_FILE_EXTENSION: &str = "properties";
+
+// Placeholders replaced in the messages
+const OPTION_PLACEHOLDER: &str = "{option}";
+const VALUE_PLACEHOLDER: &str = "{value}";
+
+/// Catalog of the messages used by CommandLineParser,
+/// messages are identified by a key (see ERROR_KEYS),
+/// a key not found in the catalog falls back to the English message.
+/// Messages can contain the {option} placeholder, replaced by the option flags,
+/// and the {value} placeholder, replaced by the value that caused the error.
+/// A catalog is written as a simple key=value text, empty lines and
+/// lines starting with # are ignored
+/// # Examples
+/// ```
+/// use rsclp::{CommandLineParser, ErrorCatalog};
+///
+/// fn main() {
+///     let catalog = ErrorCatalog::parse("\
+/// ## Italian messages
+/// missing_option_argument=manca l'argomento per l'opzione {option}
+/// ").unwrap();
+///     let mut clp = CommandLineParser::with_catalog(catalog);
+///     let _ = clp.add_string_option('c', "config", false, "file path", "configuration file path").unwrap();
+///     let error = clp.parse(&["app".to_string(), "-c".to_string()]).unwrap_err();
+///     assert_eq!("StringCommandLineOptionType -c/--config: manca l'argomento per l'opzione -c/--config", error.to_string());
+/// }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ErrorCatalog {
+    messages: HashMap<String, String>
+}
+
+impl ErrorCatalog {
+    /// Associated function to create the catalog
+    /// of the English default messages
+    pub fn english() -> Self {
+        Self::default()
+    }
+
+    /// Associated function to create a catalog from
+    /// a list of messages ordered by error message indexes
+    /// * `errors_list` - list of messages
+    pub fn from_list(errors_list: [&str; CMD_LINE_OPTION_ERROR_NUM]) -> Self {
+        let mut result = Self::default();
+        for (key, message) in ERROR_KEYS.iter().zip(errors_list) {
+            result.messages.insert(key.to_string(), message.to_string());
+        }
+        result
+    }
+
+    /// Associated function to create a catalog from
+    /// an embedded table of key, message pairs
+    /// * `table` - key, message pairs
+    pub fn from_table(table: &[(&str, &str)]) -> Self {
+        let mut result = Self::default();
+        for (key, message) in table {
+            result.messages.insert(key.to_string(), message.to_string());
+        }
+        result
+    }
+
+    /// Associated function to create a catalog from a key=value text,
+    /// an InvalidData error is returned if a not empty line
+    /// is not a comment and has no = character
+    /// * `text` - catalog text
+    pub fn parse(text: &str) -> IOResult<Self> {
+        let mut result = Self::default();
+        for (line_idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_once('=') {
+                Some((key, message)) => {
+                    result.messages.insert(key.trim().to_string(), message.trim().to_string());
+                },
+                None => {
+                    return Err(IOError::new(ErrorKind::InvalidData,
+                                format!("line {}: missing = in '{}'", line_idx + 1, line)));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Associated function to create a catalog reading a key=value file
+    /// * `path` - catalog file path
+    pub fn from_file<P: AsRef<Path>>(path: P) -> IOResult<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Associated function to create the catalog of the current locale
+    /// (see detect_locale) from a list of embedded catalog texts,
+    /// i.g. [("it", include_str!("it.properties")), ("fr", include_str!("fr.properties"))]
+    /// The English catalog is returned if no text matches the locale
+    /// * `catalogs` - locale, catalog text pairs
+    pub fn from_locale_table(catalogs: &[(&str, &str)]) -> IOResult<Self> {
+        if let Some(locale) = Self::detect_locale() {
+            for candidate in Self::locale_candidates(&locale) {
+                if let Some((_, text)) = catalogs.iter().find(|(name, _)| *name == candidate) {
+                    return Self::parse(text);
+                }
+            }
+        }
+        Ok(Self::english())
+    }
+
+    /// Associated function to create the catalog of the current locale
+    /// (see detect_locale) reading the file &ltlocale&gt.properties from a directory,
+    /// the full locale name is tried first then the language only
+    /// (i.g. it_IT.properties then it.properties).
+    /// The English catalog is returned if no file matches the locale
+    /// * `dir` - directory containing the catalog files
+    pub fn from_locale_dir<P: AsRef<Path>>(dir: P) -> IOResult<Self> {
+        if let Some(locale) = Self::detect_locale() {
+            for candidate in Self::locale_candidates(&locale) {
+                let path = dir.as_ref().join(format!("{}.{}", candidate, CATALOG_FILE_EXTENSION));
+                if path.is_file() {
+                    return Self::from_file(path);
+                }
+            }
+        }
+        Ok(Self::english())
+    }
+
+    /// Associated function to detect the current locale
+    /// from LC_ALL, LC_MESSAGES or LANG environment variables
+    /// (i.g. it_IT.UTF-8), None is returned if no one is set
+    pub fn detect_locale() -> Option<String> {
+        LOCALE_ENV_VARS.iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+    }
+
+    // Associated function that returns the names to look for
+    // a locale, full name first then language only,
+    // C and POSIX locales have no candidates
+    // * `locale` - locale name (i.g. it_IT.UTF-8@euro)
+    fn locale_candidates(locale: &str) -> Vec<String> {
+        let name = locale.split(['.', '@']).next().unwrap_or("");
+        if name.is_empty() || name == "C" || name == "POSIX" {
+            return vec![];
+        }
+        let mut result = vec![name.to_string()];
+        if let Some((language, _)) = name.split_once('_') {
+            result.push(language.to_string());
+        }
+        result
+    }
+
+    /// Method to set a message in the catalog
+    /// * `key` - message key
+    /// * `message` - message text
+    pub fn set_message(&mut self, key: &str, message: &str) {
+        self.messages.insert(key.to_string(), message.to_string());
+    }
+
+    /// Method to get a message by key with placeholders replaced,
+    /// the English message is used if the key is not in the catalog
+    /// * `key` - message key
+    /// * `option` - text replacing the {option} placeholder
+    /// * `value` - text replacing the {value} placeholder
+    pub fn get_message(&self, key: &str, option: &str, value: &str) -> String {
+        let template = match self.messages.get(key) {
+            Some(message) => message.as_str(),
+            None => ENGLISH_MESSAGES.iter()
+                        .find(|(english_key, _)| *english_key == key)
+                        .map_or(key, |(_, message)| message)
+        };
+        template.replace(OPTION_PLACEHOLDER, option).replace(VALUE_PLACEHOLDER, value)
+    }
+
+    /// Method to get a localized help text heading,
+    /// the message keyed by heading_&ltheading&gt is returned if it is in the catalog
+    /// (i.g. heading_Options=Opzioni), the heading as is otherwise
+    /// * `heading` - help text heading (i.g. Options, Examples or an option category)
+    pub fn get_heading(&self, heading: &str) -> String {
+        self.messages.get(&format!("{}{}", HEADING_KEY_PREFIX, heading))
+            .map_or(heading.to_string(), |message| message.clone())
+    }
+
+    // Method to get an error message by error message index
+    // * `error_idx` - error message index
+    // * `option` - text replacing the {option} placeholder
+    // * `value` - text replacing the {value} placeholder
+    pub(crate) fn error(&self, error_idx: usize, option: &str, value: &str) -> String {
+        self.get_message(ERROR_KEYS[error_idx], option, value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MISSING_OPTION_ARGUMENT_ERROR_IDX;
+
+    #[test]
+    fn english_fallback() {
+        let catalog = ErrorCatalog::from_table(&[("option_not_found", "opzione {value} non trovata")]);
+        assert_eq!("opzione --foo non trovata", catalog.get_message("option_not_found", "", "--foo"));
+        assert_eq!("missing option argument", catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, "-c", ""));
+    }
+
+    #[test]
+    fn parse_catalog_text() {
+        let catalog = ErrorCatalog::parse("# comment\n\nmissing_option_argument = manca l'argomento di {option}\n").unwrap();
+        assert_eq!("manca l'argomento di -c", catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, "-c", ""));
+        assert_eq!("line 2: missing = in 'wrong line'",
+                   ErrorCatalog::parse("key=value\nwrong line").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn localized_headings() {
+        let catalog = ErrorCatalog::parse("heading_Options=Opzioni\n").unwrap();
+        assert_eq!("Opzioni", catalog.get_heading("Options"));
+        assert_eq!("Network", catalog.get_heading("Network"));
+    }
+
+    #[test]
+    fn locale_candidates() {
+        assert_eq!(vec!["it_IT".to_string(), "it".to_string()], ErrorCatalog::locale_candidates("it_IT.UTF-8@euro"));
+        assert!(ErrorCatalog::locale_candidates("C.UTF-8").is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use std::env;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::result::Result as StdResult;
+
+use crate::{CommandLineParser, CommandLineParserError, Event, ParseResult, COMMAND_ALREADY_EXISTS_ERROR_KEY, COMMAND_LINE_OPTION_TYPE_UNDEFINED, COMMAND_NOT_FOUND_ERROR_KEY, MISSING_COMMAND_ERROR_KEY};
+
+// Function that returns the file name of a program,
+// without directories and executable file extension (i.g. ls for /bin/ls)
+// * `program_name` - program name (the first process argument)
+pub(crate) fn program_basename(program_name: &str) -> &str {
+    let basename = Path::new(program_name).file_name().and_then(OsStr::to_str).unwrap_or_default();
+    basename.strip_suffix(env::consts::EXE_SUFFIX).unwrap_or(basename)
+}
+
+impl ParseResult {
+    // Method that shifts the process argument indexes of a command parse result,
+    // the command parser indexes are relative to the command name;
+    // indexes of values not read from the process arguments stay 0
+    // * `offset` - index of the command name in the process arguments
+    fn shift_indexes(&mut self, offset: usize) {
+        for option in self.options.iter_mut() {
+            option.value_indexes.iter_mut().filter(|arg_index| **arg_index > 0).for_each(|arg_index| *arg_index += offset);
+        }
+        self.occurrences.iter_mut().for_each(|(arg_index, _)| *arg_index += offset);
+        self.remaining_indexes.iter_mut().for_each(|arg_index| *arg_index += offset);
+        self.positional_indexes.iter_mut().for_each(|arg_index| *arg_index += offset);
+    }
+}
+
+impl CommandLineParser {
+    /// Method to add a command (i.g. build of tool build --release),
+    /// the arguments following the command name are parsed by the command parser
+    /// (see parse_commands); an error is returned if the command already exists
+    /// * `name` - command name
+    /// * `parser` - parser of the command options
+    pub fn add_command(&mut self, name: &str, parser: CommandLineParser) -> StdResult<(), CommandLineParserError> {
+        if self.commands.iter().any(|(command_name, _)| command_name == name) {
+            return Err(CommandLineParserError::new(name.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                self.errors_catalog.get_message(COMMAND_ALREADY_EXISTS_ERROR_KEY, name, "")));
+        }
+        self.commands.push((name.to_string(), parser));
+        Ok(())
+    }
+
+    /// Method to retrieve the names of the added commands, in the adding order
+    pub fn get_command_names(&self) -> Vec<&str> {
+        self.commands.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    // Method that returns the index of the first argument that is a command name,
+    // the arguments length if there is none; option arguments and arguments
+    // following -- are never command names
+    // * `parser` - parser of the options that can precede the command name
+    // * `args` - arguments starting from the program or the previous command name
+    // * `stop_at_positional` - true to return the index of the first argument
+    //   that is not an option, even if it is not a command name
+    fn next_command_index(&self, parser: &CommandLineParser, args: &[String], stop_at_positional: bool) -> usize {
+        let mut events = parser.events(args);
+        while let Some(event) = events.next() {
+            match event {
+                Event::Positional(arg) if stop_at_positional || self.commands.iter().any(|(name, _)| *name == arg) => return events.consumed_args(),
+                Event::DashDash => break,
+                _ => {}
+            }
+        }
+        args.len()
+    }
+
+    /// Method to parse a command line made of chained commands
+    /// (i.g. tool --verbose build --release test --nocapture): the options
+    /// preceding the first command name are parsed by this parser, each command
+    /// name and the arguments up to the next command name are parsed by the
+    /// command parser (see add_command). It returns the parse result of this parser
+    /// and the commands in the process arguments order, each one with its parse
+    /// result (the command name is its program name); errors and the indexes
+    /// returned by the command parse results (i.g. get_options_order) are
+    /// the indexes of the process arguments as by parse.
+    /// If external commands are enabled (see set_external_commands) and the first
+    /// argument that is not an option is not a command name, no command is returned
+    /// and the parse result reports the external command (see ParseResult::get_external_command)
+    /// * `process_args` - process arguments as a strings slice
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let mut build = CommandLineParser::new(None);
+    ///     let release_option = build.add_long_boolean_option("release", false, "build optimized artifacts").unwrap();
+    ///     let mut test = CommandLineParser::new(None);
+    ///     let nocapture_option = test.add_long_boolean_option("nocapture", false, "show tests output").unwrap();
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     clp.add_command("build", build).unwrap();
+    ///     clp.add_command("test", test).unwrap();
+    ///     let args: Vec<String> = ["tool", "build", "--release", "test", "--nocapture"].iter().map(|arg| arg.to_string()).collect();
+    ///     let (_, commands) = clp.parse_commands(&args).unwrap();
+    ///     assert_eq!("build", commands[0].0);
+    ///     assert!(commands[0].1.is_set(&release_option));
+    ///     assert_eq!("test", commands[1].0);
+    ///     assert!(commands[1].1.is_set(&nocapture_option));
+    /// }
+    /// ```
+    pub fn parse_commands(&self, process_args: &[String]) -> StdResult<(ParseResult, Vec<(String, ParseResult)>), CommandLineParserError> {
+        let mut end = self.next_command_index(self, process_args, self.external_commands);
+        let mut result = self.parse(&process_args[..end])?;
+        let mut commands: Vec<(String, ParseResult)> = vec![];
+        if end < process_args.len() && !self.commands.iter().any(|(name, _)| *name == process_args[end]) {
+            result.external_command = Some((process_args[end].clone(), process_args[end + 1..].to_vec()));
+            return Ok((result, commands));
+        }
+        while end < process_args.len() {
+            let start = end;
+            let Some((name, parser)) = self.commands.iter().find(|(name, _)| *name == process_args[start]) else {
+                break;
+            };
+            end = start + self.next_command_index(parser, &process_args[start..], false);
+            let mut command_result = parser.parse(&process_args[start..end]).map_err(|mut error| {
+                error.arg_index = error.arg_index.map(|arg_index| arg_index + start);
+                error
+            })?;
+            command_result.shift_indexes(start);
+            commands.push((name.clone(), command_result));
+        }
+        Ok((result, commands))
+    }
+
+    /// Method to parse the command line of a multi-call program (i.g. busybox),
+    /// installed under several names: the command is chosen by the file name
+    /// of the program (i.g. ls for /bin/ls) and parses all the process arguments,
+    /// otherwise the first argument has to be a command name (i.g. busybox ls -l)
+    /// and the command parses it and the following arguments.
+    /// It returns the command name and its parse result (its indexes are the
+    /// indexes of the process arguments), a command not found error is returned
+    /// if no command matches and a missing command error if there is no argument
+    /// * `process_args` - process arguments as a strings slice
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let mut ls = CommandLineParser::new(None);
+    ///     let long_option = ls.add_short_boolean_option('l', false, "long listing format").unwrap();
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     clp.add_command("ls", ls).unwrap();
+    ///     let (name, result) = clp.parse_multi_call(&["/bin/ls".to_string(), "-l".to_string()]).unwrap();
+    ///     assert_eq!("ls", name);
+    ///     assert!(result.is_set(&long_option));
+    ///     let (name, result) = clp.parse_multi_call(&["busybox".to_string(), "ls".to_string(), "-l".to_string()]).unwrap();
+    ///     assert_eq!("ls", name);
+    ///     assert!(result.is_set(&long_option));
+    /// }
+    /// ```
+    pub fn parse_multi_call(&self, process_args: &[String]) -> StdResult<(String, ParseResult), CommandLineParserError> {
+        let program_name = process_args.first().map_or("", |program_name| program_basename(program_name));
+        if let Some((name, parser)) = self.commands.iter().find(|(name, _)| name == program_name) {
+            return Ok((name.clone(), parser.parse(process_args)?));
+        }
+        let Some(command_name) = process_args.get(1) else {
+            return Err(CommandLineParserError::new(program_name.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                self.errors_catalog.get_message(MISSING_COMMAND_ERROR_KEY, program_name, "")));
+        };
+        match self.commands.iter().find(|(name, _)| name == command_name) {
+            Some((name, parser)) => {
+                let mut result = parser.parse(&process_args[1..]).map_err(|mut error| {
+                    error.arg_index = error.arg_index.map(|arg_index| arg_index + 1);
+                    error
+                })?;
+                result.shift_indexes(1);
+                Ok((name.clone(), result))
+            },
+            None => Err(CommandLineParserError {
+                arg_index: Some(1),
+                ..CommandLineParserError::new(command_name.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                    self.errors_catalog.get_message(COMMAND_NOT_FOUND_ERROR_KEY, command_name, ""))
+            })
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chained_commands() {
+        let mut build = CommandLineParser::new(None);
+        let release_option = build.add_long_boolean_option("release", false, "build optimized artifacts").unwrap();
+        let target_option = build.add_long_string_option("target", false, "triple", "target triple").unwrap();
+        let mut test = CommandLineParser::new(None);
+        let jobs_option = test.add_short_integer_option('j', false, "jobs", "parallel jobs").unwrap();
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_short_boolean_option('v', false, "verbose output").unwrap();
+        clp.add_command("build", build).unwrap();
+        clp.add_command("test", test.clone()).unwrap();
+        assert_eq!("UndefinedCommandLineOptionType test: command already exists", clp.add_command("test", test).unwrap_err().to_string());
+        assert_eq!(vec!["build", "test"], clp.get_command_names());
+
+        let args: Vec<String> = ["tool", "-v", "build", "--target", "test", "--release", "test", "-j", "4", "name", "build"]
+                                    .iter().map(|arg| arg.to_string()).collect();
+        let (result, commands) = clp.parse_commands(&args).unwrap();
+        assert!(result.is_set(&verbose_option));
+        assert_eq!(3, commands.len());
+        assert_eq!("build", commands[0].0);
+        assert_eq!("test", commands[0].1.get_value::<String>(&target_option).unwrap());
+        assert!(commands[0].1.is_set(&release_option));
+        assert_eq!("test", commands[1].1.get_program_name());
+        assert_eq!(4, commands[1].1.get_value::<i32>(&jobs_option).unwrap());
+        assert_eq!(&vec!["name".to_string()], commands[1].1.get_remaining_args());
+        assert_eq!("build", commands[2].0);
+        assert!(!commands[2].1.is_set(&release_option));
+        assert_eq!(&vec![(3, target_option), (5, release_option)], commands[0].1.get_options_order());
+        assert_eq!(Some(vec![(4, "test".to_string())]), commands[0].1.get_values_with_index::<String>(&target_option));
+        assert_eq!(Some(vec![(8, 4)]), commands[1].1.get_values_with_index::<i32>(&jobs_option));
+        assert_eq!(vec![(9, "name")], commands[1].1.get_remaining_args_with_index());
+
+        let args: Vec<String> = ["tool", "build", "test", "-j"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(Some(3), clp.parse_commands(&args).unwrap_err().get_arg_index());
+        let args: Vec<String> = ["tool", "-v"].iter().map(|arg| arg.to_string()).collect();
+        assert!(clp.parse_commands(&args).unwrap().1.is_empty());
+    }
+
+    #[test]
+    fn multi_call_commands() {
+        let mut ls = CommandLineParser::new(None);
+        let all_option = ls.add_short_boolean_option('a', false, "show hidden files").unwrap();
+        let mut cat = CommandLineParser::new(None);
+        let number_option = cat.add_short_boolean_option('n', false, "number lines").unwrap();
+        let mut clp = CommandLineParser::new(None);
+        clp.add_command("ls", ls).unwrap();
+        clp.add_command("cat", cat).unwrap();
+        assert_eq!("ls", program_basename("/usr/local/bin/ls"));
+
+        let args: Vec<String> = ["./cat", "-n", "file"].iter().map(|arg| arg.to_string()).collect();
+        let (name, result) = clp.parse_multi_call(&args).unwrap();
+        assert_eq!(("cat", "./cat"), (name.as_str(), result.get_program_name()));
+        assert!(result.is_set(&number_option));
+        assert_eq!(&vec!["file".to_string()], result.get_remaining_args());
+
+        let args: Vec<String> = ["/bin/busybox", "ls", "-a"].iter().map(|arg| arg.to_string()).collect();
+        let (name, result) = clp.parse_multi_call(&args).unwrap();
+        assert_eq!(("ls", "ls"), (name.as_str(), result.get_program_name()));
+        assert!(result.is_set(&all_option));
+        assert_eq!(&vec![(2, all_option)], result.get_options_order());
+
+        let args: Vec<String> = ["busybox", "cp", "a", "b"].iter().map(|arg| arg.to_string()).collect();
+        let error = clp.parse_multi_call(&args).unwrap_err();
+        assert_eq!(("UndefinedCommandLineOptionType cp: command not found".to_string(), Some(1)), (error.to_string(), error.get_arg_index()));
+        let error = clp.parse_multi_call(&["/bin/busybox".to_string()]).unwrap_err();
+        assert_eq!(("UndefinedCommandLineOptionType busybox: missing command".to_string(), None), (error.to_string(), error.get_arg_index()));
+    }
+}
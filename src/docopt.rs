@@ -0,0 +1,515 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::Result as IOResult;
+
+use crate::spec::spec_error;
+use crate::{CommandLineParser, ParseResult};
+
+// Section headers of a docopt text (compared in lower case)
+const USAGE_SECTION: &str = "usage:";
+const OPTIONS_SECTION: &str = "options:";
+
+// Usage pattern shortcut standing for all the described options
+const OPTIONS_SHORTCUT: &str = "[options]";
+
+// Usage pattern grouping, alternative and repetition tokens
+const OPTIONAL_GROUP_START: &str = "[";
+const OPTIONAL_GROUP_END: &str = "]";
+const REQUIRED_GROUP_START: &str = "(";
+const REQUIRED_GROUP_END: &str = ")";
+const ALTERNATIVE: &str = "|";
+const REPETITION: &str = "...";
+
+// Separator between flags and description in an options section line
+const DESCRIPTION_SEPARATOR: &str = "  ";
+
+// Default value marker of an options section line (compared in lower case)
+const DEFAULT_VALUE_START: &str = "[default:";
+
+// Value of a command found in the process arguments
+const COMMAND_VALUE: &str = "true";
+
+// Usage pattern argument that is not an option
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) enum DocArgument {
+    // command (i.g. ship) and if it is required
+    Command(String, bool),
+    // positional argument (i.g. <name>), if it is required and if it is repeated
+    Positional(String, bool, bool),
+}
+
+impl DocArgument {
+    // Method that returns the argument name (i.g. ship or <name>)
+    fn get_name(&self) -> &str {
+        match self {
+            Self::Command(name, _) | Self::Positional(name, _, _) => name
+        }
+    }
+}
+
+// Docopt text parts used after the parsing phase:
+// the arguments of each usage pattern and the options default values
+#[derive(Clone, Default)]
+pub(crate) struct DocoptDefinition {
+    patterns: Vec<Vec<DocArgument>>,
+    defaults: Vec<(u64, String)>,
+}
+
+// Command line option as described by a docopt text
+struct DocOption {
+    line: usize,
+    short_form_option: Option<char>,
+    long_form_option: String,
+    arg_text: String,
+    help_text: String,
+    default_value: Option<String>,
+    // for each usage pattern true if the option is required by the pattern
+    required_by: Vec<bool>,
+}
+
+impl DocOption {
+    // Method that returns if the option has a flag
+    // * `flag` - flag to check (i.g. -c or --config)
+    fn has_flag(&self, flag: &str) -> bool {
+        match flag.strip_prefix("--") {
+            Some(long) => self.long_form_option == long,
+            None => self.short_form_option.is_some_and(|short| flag == format!("-{}", short))
+        }
+    }
+
+    // Method that returns the option flags as map keys
+    fn get_flags(&self) -> Vec<String> {
+        let mut flags = vec![];
+        if let Some(short) = self.short_form_option {
+            flags.push(format!("-{}", short));
+        }
+        if !self.long_form_option.is_empty() {
+            flags.push(format!("--{}", self.long_form_option));
+        }
+        flags
+    }
+}
+
+// Function that returns the argument text of a docopt argument
+// (<file> or FILE), None if the token is not an argument
+// * `token` - token to check
+fn arg_text_of(token: &str) -> Option<String> {
+    if let Some(arg_text) = token.strip_prefix('<').and_then(|token| token.strip_suffix('>')) {
+        return Some(arg_text.to_string());
+    }
+    let is_upper = token.chars().any(|character| character.is_ascii_uppercase()) &&
+                    token.chars().all(|character| character.is_ascii_uppercase() || character.is_ascii_digit() ||
+                                                    '_' == character || '-' == character);
+    is_upper.then(|| token.to_string())
+}
+
+// Function that returns the default value of an option
+// written in its description (i.g. Speed in knots [default: 10].)
+// * `help_text` - option description
+fn default_value_of(help_text: &str) -> Option<String> {
+    let (marker_idx, _) = help_text.char_indices().find(|(idx, _)| {
+        help_text.as_bytes()[*idx..].get(..DEFAULT_VALUE_START.len())
+            .is_some_and(|marker| marker.eq_ignore_ascii_case(DEFAULT_VALUE_START.as_bytes()))
+    })?;
+    let value = &help_text[marker_idx + DEFAULT_VALUE_START.len()..];
+    Some(value[..value.find(']')?].trim().to_string())
+}
+
+// Function that returns the identifier of a usage pattern argument
+// * `name` - argument name (i.g. ship or <name>)
+fn argument_hash(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Function that matches the remaining arguments with the arguments of
+// a usage pattern, it returns the value of each matched argument or
+// None if the remaining arguments do not follow the pattern
+// * `arguments` - usage pattern arguments
+// * `args` - remaining arguments
+fn match_arguments<'a>(arguments: &'a [DocArgument], args: &[String]) -> Option<Vec<(&'a str, String)>> {
+    let Some((argument, rest)) = arguments.split_first() else {
+        return args.is_empty().then(Vec::new);
+    };
+    let (min_count, max_count) = match argument {
+        DocArgument::Command(name, required) => {
+            if args.first() == Some(name) {
+                if let Some(mut values) = match_arguments(rest, &args[1..]) {
+                    values.insert(0, (name.as_str(), COMMAND_VALUE.to_string()));
+                    return Some(values);
+                }
+            }
+            return if *required { None } else { match_arguments(rest, args) };
+        },
+        DocArgument::Positional(_, required, repeated) => {
+            (usize::from(*required), if *repeated { args.len() } else { 1.min(args.len()) })
+        }
+    };
+    for count in (min_count..=max_count).rev() {
+        if let Some(mut values) = match_arguments(rest, &args[count..]) {
+            let matched = args[..count].iter().map(|arg| (argument.get_name(), arg.clone()));
+            values.splice(0..0, matched);
+            return Some(values);
+        }
+    }
+    None
+}
+
+// Function that splits a usage pattern in tokens,
+// brackets, parentheses, alternatives and repetitions are separate tokens
+// * `pattern` - usage pattern
+fn tokenize(pattern: &str) -> Vec<String> {
+    let pattern = pattern.replace(OPTIONS_SHORTCUT, " ")
+                    .replace(REPETITION, &format!(" {} ", REPETITION));
+    let mut tokens = vec![];
+    for word in pattern.split_whitespace() {
+        let mut token = String::new();
+        for character in word.chars() {
+            if "[]()|".contains(character) {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                tokens.push(character.to_string());
+            } else {
+                token.push(character);
+            }
+        }
+        if !token.is_empty() {
+            tokens.push(token);
+        }
+    }
+    tokens
+}
+
+// Function that returns for each token of a usage pattern
+// if it is required: tokens inside [] or inside a group with
+// alternatives (i.g. (-a | -b)) are optional
+// * `tokens` - usage pattern tokens
+fn required_tokens(tokens: &[String]) -> Vec<bool> {
+    let mut result = vec![true; tokens.len()];
+    // open groups: optional flag, alternatives flag, tokens indexes
+    let mut groups: Vec<(bool, bool, Vec<usize>)> = vec![(false, false, vec![])];
+    for (token_idx, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            OPTIONAL_GROUP_START => groups.push((true, false, vec![])),
+            REQUIRED_GROUP_START => groups.push((false, false, vec![])),
+            OPTIONAL_GROUP_END | REQUIRED_GROUP_END if groups.len() > 1 => {
+                let (optional, alternatives, indexes) = groups.pop().unwrap();
+                if optional || alternatives {
+                    indexes.iter().for_each(|idx| result[*idx] = false);
+                }
+                groups.last_mut().unwrap().2.extend(indexes);
+            },
+            ALTERNATIVE => groups.last_mut().unwrap().1 = true,
+            _ => groups.last_mut().unwrap().2.push(token_idx)
+        }
+    }
+    for (_, alternatives, indexes) in groups {
+        if alternatives {
+            indexes.iter().for_each(|idx| result[*idx] = false);
+        }
+    }
+    result
+}
+
+// Function that parses an options section line
+// (i.g. -c <file>, --config=<file>  Configuration file.)
+// * `line_number` - docopt text line number
+// * `line` - trimmed options section line
+fn parse_option_line(line_number: usize, line: &str) -> IOResult<DocOption> {
+    let (flags, help_text) = line.split_once(DESCRIPTION_SEPARATOR).unwrap_or((line, ""));
+    let mut option = DocOption {
+        line: line_number,
+        short_form_option: None,
+        long_form_option: String::new(),
+        arg_text: String::new(),
+        help_text: help_text.trim().trim_end_matches('.').to_string(),
+        default_value: default_value_of(help_text),
+        required_by: vec![],
+    };
+    for token in flags.replace([',', '='], " ").split_whitespace() {
+        if let Some(long) = token.strip_prefix("--") {
+            option.long_form_option = long.to_string();
+        } else if let Some(short) = token.strip_prefix('-') {
+            let mut chars = short.chars();
+            option.short_form_option = chars.next();
+            let attached_arg = chars.as_str();
+            if !attached_arg.is_empty() {
+                option.arg_text = arg_text_of(attached_arg).unwrap_or(attached_arg.to_string());
+            }
+        } else if let Some(arg_text) = arg_text_of(token) {
+            option.arg_text = arg_text;
+        } else {
+            return Err(spec_error(line_number, &format!("'{}' is not a flag or an argument", token)));
+        }
+    }
+    if option.short_form_option.is_none() && option.long_form_option.is_empty() {
+        return Err(spec_error(line_number, &format!("'{}' has no flags", line)));
+    }
+    Ok(option)
+}
+
+impl CommandLineParser {
+    /// Associated function to create a command line parser from a docopt
+    /// usage and help text, it returns the parser and a map from each option flag
+    /// (i.g. -c and --config) to the option identifier, values are read by get_value.
+    /// The text has to contain a Usage: section, one usage pattern per line up to
+    /// an empty line, and can contain an Options: section describing the options,
+    /// one per line (i.g. -c &ltfile&gt, --config=&ltfile&gt  Configuration file.).
+    /// Options with an argument (&ltfile&gt or FILE) are string options, the others are
+    /// boolean options, -h/--help is the help option. An option is mandatory
+    /// if every usage pattern requires it (not inside [] or an alternative).
+    /// Options can be repeated (...) and their values are returned by get_values,
+    /// a [default: value] in an option description is its value when it is not set.
+    /// The map also contains the usage patterns arguments (i.g. &ltinput&gt, FILE or
+    /// the ship command) read by is_set, get_value and get_values as options: after parsing,
+    /// the remaining arguments are matched with the first usage pattern they follow,
+    /// a found command has the true value, they are returned by get_remaining_args too.
+    /// An InvalidData error with the text line number is returned if the text is not valid
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let (clp, options) = CommandLineParser::from_docopt("\
+    /// Usage: tool [-v] --config=<file> <input>...
+    ///
+    /// Options:
+    ///   -v, --verbose          Verbose output.
+    ///   -c, --config=<file>    Configuration file.
+    /// ").unwrap();
+    ///     let args: Vec<String> = ["tool", "-c", "app.properties", "a.txt"].iter().map(|arg| arg.to_string()).collect();
+    ///     let result = clp.parse(&args).unwrap();
+    ///     assert_eq!("app.properties", result.get_value::<String>(&options["--config"]).unwrap());
+    ///     assert!(!result.is_set(&options["-v"]));
+    ///     assert_eq!(Some(vec!["a.txt".to_string()]), result.get_values::<String>(&options["<input>"]));
+    /// }
+    /// ```
+    pub fn from_docopt(doc: &str) -> IOResult<(Self, HashMap<String, u64>)> {
+        let mut patterns: Vec<(usize, String)> = vec![];
+        let mut doc_options: Vec<DocOption> = vec![];
+        let mut in_usage = false;
+        let mut in_options = false;
+        for (line_idx, line) in doc.lines().enumerate() {
+            let line_number = line_idx + 1;
+            let trimmed = line.trim();
+            let lower = trimmed.to_lowercase();
+            if lower.starts_with(USAGE_SECTION) {
+                in_usage = true;
+                let pattern = trimmed[USAGE_SECTION.len()..].trim();
+                if !pattern.is_empty() {
+                    patterns.push((line_number, pattern.to_string()));
+                }
+            } else if lower.ends_with(OPTIONS_SECTION) {
+                in_usage = false;
+                in_options = true;
+            } else if trimmed.is_empty() {
+                in_usage = false;
+            } else if in_usage {
+                patterns.push((line_number, trimmed.to_string()));
+            } else if in_options && trimmed.starts_with('-') {
+                doc_options.push(parse_option_line(line_number, trimmed)?);
+            }
+        }
+        if patterns.is_empty() {
+            return Err(spec_error(doc.lines().count().max(1), "missing usage patterns"));
+        }
+        let mut program_name = String::new();
+        let mut docopt = DocoptDefinition::default();
+        for (pattern_idx, (line_number, pattern)) in patterns.iter().enumerate() {
+            let mut arguments = vec![];
+            let tokens = tokenize(pattern);
+            let required = required_tokens(&tokens);
+            program_name = tokens.first().cloned().unwrap_or_default();
+            let mut token_idx = 1;
+            while token_idx < tokens.len() {
+                let token = tokens[token_idx].as_str();
+                let is_required = required[token_idx];
+                token_idx += 1;
+                let mut flags: Vec<(String, String)> = vec![];
+                if let Some(long) = token.strip_prefix("--").filter(|long| !long.is_empty()) {
+                    let (long, arg) = long.split_once('=').unwrap_or((long, ""));
+                    flags.push((format!("--{}", long), arg_text_of(arg).unwrap_or(arg.to_string())));
+                } else if let Some(shorts) = token.strip_prefix('-').filter(|shorts| !shorts.is_empty()) {
+                    flags.extend(shorts.chars().map(|short| (format!("-{}", short), String::new())));
+                } else if arg_text_of(token).is_some() {
+                    let repeated = tokens.get(token_idx).is_some_and(|next| REPETITION == next);
+                    arguments.push(DocArgument::Positional(token.to_string(), is_required, repeated));
+                } else if !"[]()|".contains(token) && REPETITION != token && "--" != token {
+                    arguments.push(DocArgument::Command(token.to_string(), is_required));
+                }
+                for (flag, arg_text) in flags {
+                    let option_idx = match doc_options.iter().position(|option| option.has_flag(&flag)) {
+                        Some(option_idx) => option_idx,
+                        None => {
+                            let mut option = parse_option_line(*line_number, &flag)?;
+                            option.arg_text = arg_text;
+                            doc_options.push(option);
+                            doc_options.len() - 1
+                        }
+                    };
+                    let option = &mut doc_options[option_idx];
+                    option.required_by.resize(patterns.len(), false);
+                    option.required_by[pattern_idx] |= is_required;
+                    // the argument of an option written as -c <file> is not a positional argument
+                    if !option.arg_text.is_empty() && !token.contains('=') && tokens.get(token_idx).is_some_and(|next| arg_text_of(next).is_some()) {
+                        token_idx += 1;
+                    }
+                }
+            }
+            docopt.patterns.push(arguments);
+        }
+
+        let mut clp = CommandLineParser::new(None);
+        clp.set_program_name(&program_name);
+        if 1 == patterns.len() {
+            clp.set_usage(&patterns[0].1);
+        }
+        let mut options: HashMap<String, u64> = HashMap::new();
+        for option in &doc_options {
+            let mandatory = !option.required_by.is_empty() && option.required_by.iter().all(|required| *required);
+            let long = option.long_form_option.as_str();
+            let (arg_text, help_text) = (option.arg_text.as_str(), option.help_text.as_str());
+            let added = match (option.short_form_option, arg_text.is_empty()) {
+                (Some('h'), true) if long == "help" => clp.add_help_option(help_text),
+                (Some(short), true) if long.is_empty() => clp.add_short_boolean_option(short, mandatory, help_text),
+                (Some(short), true) => clp.add_boolean_option(short, long, mandatory, help_text),
+                (None, true) => clp.add_long_boolean_option(long, mandatory, help_text),
+                (Some(short), false) if long.is_empty() => clp.add_short_string_option(short, mandatory, arg_text, help_text),
+                (Some(short), false) => clp.add_string_option(short, long, mandatory, arg_text, help_text),
+                (None, false) => clp.add_long_string_option(long, mandatory, arg_text, help_text)
+            };
+            let option_hash = added.map_err(|error| spec_error(option.line, &error.to_string()))?;
+            for flag in option.get_flags() {
+                options.insert(flag, option_hash);
+            }
+            if let Some(default_value) = option.default_value.as_ref().filter(|_| !arg_text.is_empty()) {
+                docopt.defaults.push((option_hash, default_value.clone()));
+            }
+        }
+        for argument in docopt.patterns.iter().flatten() {
+            options.insert(argument.get_name().to_string(), argument_hash(argument.get_name()));
+        }
+        clp.docopt = docopt;
+        Ok((clp, options))
+    }
+
+    // Method that completes a parse result with the docopt text parts:
+    // unset options get their default value (with index 0 as prompted ones)
+    // and the remaining arguments are matched with the usage patterns arguments
+    // * `result` - parse result to complete
+    pub(crate) fn apply_docopt(&self, result: &mut ParseResult) {
+        for (option_hash, default_value) in &self.docopt.defaults {
+            let option = result.options.iter_mut().find(|option| *option_hash == option.calculate_hash());
+            if let Some(option) = option.filter(|option| !option.is_set()) {
+                let _ = option.add_value(default_value, 0);
+            }
+        }
+        let mut arguments: Vec<(u64, String, Vec<String>)> = vec![];
+        for argument in self.docopt.patterns.iter().flatten() {
+            if !arguments.iter().any(|(_, name, _)| name == argument.get_name()) {
+                arguments.push((argument_hash(argument.get_name()), argument.get_name().to_string(), vec![]));
+            }
+        }
+        let matched = self.docopt.patterns.iter().find_map(|pattern| match_arguments(pattern, &result.remaining_args));
+        for (name, value) in matched.unwrap_or_default() {
+            if let Some((_, _, values)) = arguments.iter_mut().find(|(_, argument_name, _)| argument_name == name) {
+                values.push(value);
+            }
+        }
+        result.docopt_arguments = arguments;
+    }
+}
+
+impl ParseResult {
+    // Method that returns the name and the values of a docopt usage pattern
+    // argument, None if the identifier is not one of a usage pattern argument
+    // * `argument_hash` - argument identifier returned by from_docopt
+    pub(crate) fn get_docopt_argument(&self, argument_hash: &u64) -> Option<(&str, &Vec<String>)> {
+        self.docopt_arguments.iter()
+            .find(|(hash, _, _)| argument_hash == hash)
+            .map(|(_, name, values)| (name.as_str(), values))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parser_from_docopt() {
+        let (clp, options) = CommandLineParser::from_docopt("\
+Naval fate.
+
+Usage:
+  naval_fate ship <name> move <x> <y> --speed=<kn> [-v...]
+  naval_fate mine (--moored | --drifting) --speed=<kn>
+
+Options:
+  -h --help       Show this screen.
+  --speed=<kn>    Speed in knots [default: 10].
+  -v, --verbose   Verbose output.
+").unwrap();
+        assert_eq!(13, options.len());
+        assert_eq!(options["-v"], options["--verbose"]);
+        let args: Vec<String> = ["naval_fate", "mine", "--moored", "-vv"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert!(result.check_mandatory_options().is_ok());
+        assert_eq!("10", result.get_value::<String>(&options["--speed"]).unwrap());
+        assert_eq!(Some(vec![(0, 10)]), result.get_values_with_index::<i32>(&options["--speed"]));
+        assert!(result.get_value::<bool>(&options["mine"]).unwrap());
+        assert!(!result.is_set(&options["ship"]));
+        assert_eq!("UndefinedCommandLineOptionType ship: is not set", result.get_value::<bool>(&options["ship"]).unwrap_err().to_string());
+        let args: Vec<String> = ["naval_fate", "ship", "guardian", "move", "10", "20", "--speed=15", "-v", "-v"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!("15", result.get_value::<String>(&options["--speed"]).unwrap());
+        assert_eq!(2, result.get_values::<bool>(&options["-v"]).unwrap().len());
+        assert!(!result.is_set(&options["--moored"]));
+        assert!(result.is_set(&options["ship"]) && result.is_set(&options["move"]) && !result.is_set(&options["mine"]));
+        assert_eq!("guardian", result.get_value::<String>(&options["<name>"]).unwrap());
+        assert_eq!(Some(vec![10, 20]), result.get_values::<i32>(&options["<x>"]).zip(result.get_values::<i32>(&options["<y>"])).map(|(x, y)| [x, y].concat()));
+        assert_eq!(5, result.get_remaining_args().len());
+    }
+
+    #[test]
+    fn usage_arguments() {
+        let (clp, options) = CommandLineParser::from_docopt("\
+Usage: copy [-f] <source>... <target>
+       copy --list [DIR]
+").unwrap();
+        let args: Vec<String> = ["copy", "a.txt", "-f", "b.txt", "out"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(Some(vec!["a.txt".to_string(), "b.txt".to_string()]), result.get_values::<String>(&options["<source>"]));
+        assert_eq!("out", result.get_value::<String>(&options["<target>"]).unwrap());
+        assert!(!result.is_set(&options["DIR"]));
+        let args: Vec<String> = ["copy", "--list", "docs"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!("docs", result.get_value::<String>(&options["DIR"]).unwrap());
+        assert!(!result.is_set(&options["<target>"]));
+    }
+
+    #[test]
+    fn non_ascii_default_value() {
+        assert_eq!(Some("é".to_string()), default_value_of("İ speed [default:é]."));
+        assert_eq!(Some("fast".to_string()), default_value_of("Speed [Default: fast]."));
+        assert_eq!(None, default_value_of("İ speed."));
+        let (clp, options) = CommandLineParser::from_docopt("\
+Usage: tool [--speed=<kn>]
+
+Options:
+  -s, --speed=<kn>  İ speed [default:é].
+").unwrap();
+        let result = clp.parse(&["tool".to_string()]).unwrap();
+        assert_eq!("é", result.get_value::<String>(&options["-s"]).unwrap());
+    }
+
+    #[test]
+    fn usage_required_tokens() {
+        let tokens = tokenize("tool [-v] (-a | -b) --config=<file> <input>...");
+        assert_eq!(vec!["tool", "[", "-v", "]", "(", "-a", "|", "-b", ")", "--config=<file>", "<input>", "..."], tokens);
+        assert_eq!(vec![true, true, false, true, true, false, true, false, true, true, true, true], required_tokens(&tokens));
+        assert_eq!("line 1: missing usage patterns", CommandLineParser::from_docopt("Tool.").err().unwrap().to_string());
+    }
+}
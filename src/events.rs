@@ -0,0 +1,272 @@
+use std::collections::VecDeque;
+
+use crate::{CommandLineOption, CommandLineParser, OptionArgument, ParsingMode, COMMAND_LINE_OPTION_TYPE_BOOLEAN, OPTION_ASSIGN_TAG};
+
+/// Event yielded by the Events iterator, in the process arguments order
+#[derive(PartialEq, Clone, Debug)]
+pub enum Event {
+    /// Single character option with its argument, if any (i.g. -c app.properties)
+    Short(char, Option<String>),
+    /// Long text option with its argument, if any (i.g. --config=app.properties)
+    Long(String, Option<String>),
+    /// Argument that is not an option or that follows -- (or the first
+    /// non-option argument in PosixParsingMode)
+    Positional(String),
+    /// The -- argument ending the options, in DefaultParsingMode
+    /// -- is the long text option named -- (i.g. Long("--", None))
+    DashDash,
+}
+
+// Kind of a process argument, shared by the parse methods and the Events iterator
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum ArgumentKind {
+    // The -- argument ending the options (not in DefaultParsingMode)
+    EndOfOptions,
+    // Long text option (i.g. --config=app.properties), in DefaultParsingMode
+    // -- is the long text option named --
+    LongOption,
+    // Single character options (i.g. -xvf)
+    ShortOption,
+    // Argument that is not an option, - included
+    Operand,
+}
+
+/// Iterator over the options and arguments of a command line,
+/// created by CommandLineParser::events. The options definition of the parser
+/// is used to know which options take an argument, unknown options
+/// and boolean options get an argument only if it is attached (i.g. -v=false).
+/// Values are not checked, errors are reported by the parse methods only
+/// # Examples
+/// ```
+/// use rsclp::{CommandLineParser, Event};
+///
+/// fn main() {
+///     let mut clp = CommandLineParser::new(None);
+///     let _ = clp.add_long_string_option("include", false, "pattern", "files to include").unwrap();
+///     let _ = clp.add_long_string_option("exclude", false, "pattern", "files to exclude").unwrap();
+///     let args: Vec<String> = ["find", "--include", "a", "--exclude", "b", "--include=c"].iter().map(|arg| arg.to_string()).collect();
+///     let events: Vec<Event> = clp.events(&args).collect();
+///     assert_eq!(vec![Event::Long("include".to_string(), Some("a".to_string())),
+///                     Event::Long("exclude".to_string(), Some("b".to_string())),
+///                     Event::Long("include".to_string(), Some("c".to_string()))], events);
+/// }
+/// ```
+pub struct Events<'a> {
+    parser: &'a CommandLineParser,
+    args: &'a [String],
+    idx: usize,
+    parsing_mode: ParsingMode,
+    options_ended: bool,
+    pending: VecDeque<Event>,
+}
+
+impl Events<'_> {
+    // Method that returns the number of process arguments
+    // consumed so far, the program name excluded
+    pub(crate) fn consumed_args(&self) -> usize {
+        self.idx
+    }
+
+    // Method that returns the argument kind of an option,
+    // unknown and boolean options have no argument
+    // * `option` - option found by flag
+    fn argument_of(option: Option<&CommandLineOption>) -> OptionArgument {
+        match option {
+            Some(option) if option.get_type_name() != COMMAND_LINE_OPTION_TYPE_BOOLEAN => {
+                if option.optional_argument {
+                    OptionArgument::OptionalArgument
+                } else {
+                    OptionArgument::RequiredArgument
+                }
+            },
+            _ => OptionArgument::NoArgument
+        }
+    }
+
+    // Method that returns the next process argument
+    // as the argument of an option, if any
+    fn next_arg(&mut self) -> Option<String> {
+        let arg = self.args.get(self.idx).cloned();
+        if arg.is_some() {
+            self.idx += 1;
+        }
+        arg
+    }
+
+    // Method to queue the events of a long text option argument
+    // * `arg` - process argument (i.g. --config=app.properties)
+    fn long_events(&mut self, arg: &str) {
+        let (mut name, value) = match arg[2..].split_once(OPTION_ASSIGN_TAG) {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (arg[2..].to_string(), None)
+        };
+        if name.is_empty() {
+            name.push_str("--");
+        }
+        let option = self.parser.options.iter().find(|option| option.has_long_form(&name));
+        let value = match (value, Self::argument_of(option)) {
+            (None, OptionArgument::RequiredArgument) => self.next_arg(),
+            (value, _) => value
+        };
+        self.pending.push_back(Event::Long(name, value));
+    }
+
+    // Method to queue the events of a single character options argument
+    // * `arg` - process argument (i.g. -xvf, -c=app.properties or -capp.properties for getopt parsers)
+    fn short_events(&mut self, arg: &str) {
+        let (flags, mut assigned) = match arg[1..].split_once(OPTION_ASSIGN_TAG) {
+            Some((flags, value)) if !self.parser.getopt_compatible => (flags.to_string(), Some(value.to_string())),
+            _ => (arg[1..].to_string(), None)
+        };
+        let chars: Vec<char> = flags.chars().collect();
+        for (char_idx, short_form_option) in chars.iter().enumerate() {
+            let option = self.parser.options.iter().find(|option| option.has_short_form(*short_form_option));
+            let argument = Self::argument_of(option);
+            if self.parser.getopt_compatible && OptionArgument::NoArgument != argument {
+                let rest: String = chars[char_idx + 1..].iter().collect();
+                let value = match (rest.is_empty(), argument) {
+                    (false, _) => Some(rest),
+                    (true, OptionArgument::RequiredArgument) => self.next_arg(),
+                    _ => None
+                };
+                self.pending.push_back(Event::Short(*short_form_option, value));
+                return;
+            }
+            let is_last = char_idx + 1 == chars.len();
+            let value = match argument {
+                OptionArgument::NoArgument if is_last => assigned.take(),
+                OptionArgument::NoArgument => None,
+                OptionArgument::OptionalArgument => assigned.take(),
+                OptionArgument::RequiredArgument => assigned.take().or_else(|| self.next_arg())
+            };
+            self.pending.push_back(Event::Short(*short_form_option, value));
+        }
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        while self.pending.is_empty() {
+            let arg = self.next_arg()?;
+            if self.options_ended {
+                return Some(Event::Positional(arg));
+            }
+            match self.parser.argument_kind(&arg, self.parsing_mode) {
+                ArgumentKind::EndOfOptions => {
+                    self.options_ended = true;
+                    return Some(Event::DashDash);
+                },
+                ArgumentKind::LongOption => self.long_events(&arg),
+                ArgumentKind::ShortOption => self.short_events(&arg),
+                ArgumentKind::Operand => {
+                    self.options_ended = ParsingMode::PosixParsingMode == self.parsing_mode;
+                    return Some(Event::Positional(arg));
+                }
+            }
+        }
+        self.pending.pop_front()
+    }
+}
+
+impl CommandLineParser {
+    // Method that returns the kind of a process argument
+    // * `arg` - process argument
+    // * `parsing_mode` - parsing mode in use (see get_parsing_mode)
+    pub(crate) fn argument_kind(&self, arg: &str, parsing_mode: ParsingMode) -> ArgumentKind {
+        if arg == "--" && ParsingMode::DefaultParsingMode != parsing_mode {
+            ArgumentKind::EndOfOptions
+        } else if arg.starts_with("--") {
+            ArgumentKind::LongOption
+        } else if arg.starts_with('-') && arg != "-" {
+            ArgumentKind::ShortOption
+        } else {
+            ArgumentKind::Operand
+        }
+    }
+
+    /// Method that returns an iterator over the events (options, positional
+    /// arguments and --) of the process arguments, in the process arguments order,
+    /// the first argument is the program name and it is skipped (see Events)
+    /// * `process_args` - process arguments as a strings slice
+    pub fn events<'a>(&'a self, process_args: &'a [String]) -> Events<'a> {
+        Events {
+            parser: self,
+            args: process_args.get(1..).unwrap_or(&[]),
+            idx: 0,
+            parsing_mode: self.get_parsing_mode(),
+            options_ended: false,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_options_events() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_short_boolean_option('x', false, "extract").unwrap();
+        let _ = clp.add_short_string_option('f', false, "file", "archive file").unwrap();
+        clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        let args: Vec<String> = ["tar", "-xf", "a.tgz", "-x=false", "dir", "-", "--", "-x"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(vec![Event::Short('x', None), Event::Short('f', Some("a.tgz".to_string())),
+                        Event::Short('x', Some("false".to_string())), Event::Positional("dir".to_string()),
+                        Event::Positional("-".to_string()), Event::DashDash, Event::Positional("-x".to_string())],
+                   clp.events(&args).collect::<Vec<Event>>());
+    }
+
+    // Function that checks that events and parse agree on the options
+    // and on the arguments that are not options of a command line
+    // * `clp` - parser to check
+    // * `args` - process arguments
+    fn assert_same_as_parse(clp: &CommandLineParser, args: &[&str]) {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let events: Vec<Event> = clp.events(&args).collect();
+        let result = clp.parse(&args).unwrap();
+        let event_args: Vec<String> = events.iter().filter_map(|event| match event {
+            Event::Positional(arg) => Some(arg.clone()),
+            _ => None
+        }).collect();
+        let mut parse_args = result.get_remaining_args().clone();
+        parse_args.extend(result.get_positional_args().iter().cloned());
+        assert_eq!(parse_args, event_args);
+        let options = events.iter().filter(|event| matches!(event, Event::Short(_, _) | Event::Long(_, _))).count();
+        assert_eq!(result.occurrences.len(), options);
+    }
+
+    #[test]
+    fn events_as_parse() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_short_boolean_option('x', false, "extract").unwrap();
+        let _ = clp.add_string_option('f', "file", false, "file", "archive file").unwrap();
+        assert_same_as_parse(&clp, &["tar", "-", "-x", "a"]);
+        assert_same_as_parse(&clp, &["tar", "-xf", "-", "--file=b", "c", "-x"]);
+        let args: Vec<String> = ["tar", "--", "-x"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(vec![Event::Long("--".to_string(), None), Event::Short('x', None)], clp.events(&args).collect::<Vec<Event>>());
+        assert_eq!("UndefinedCommandLineOptionType --: option not found", clp.parse(&args).unwrap_err().to_string());
+
+        clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        assert_same_as_parse(&clp, &["tar", "-", "-x", "--", "-x", "--"]);
+        clp.set_parsing_mode(ParsingMode::PosixParsingMode);
+        assert_same_as_parse(&clp, &["tar", "-x", "-", "-x"]);
+        assert_same_as_parse(&clp, &["tar", "-f", "-", "--", "-x"]);
+
+        let (clp, _) = CommandLineParser::from_getopt("+ab:").unwrap();
+        assert_same_as_parse(&clp, &["tool", "-abvalue", "-", "-a"]);
+    }
+
+    #[test]
+    fn getopt_events() {
+        let (clp, _) = CommandLineParser::from_getopt("+ab:c::").unwrap();
+        let args: Vec<String> = ["tool", "-abvalue", "-c", "-b", "x", "--unknown", "cmd", "-a"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(vec![Event::Short('a', None), Event::Short('b', Some("value".to_string())), Event::Short('c', None),
+                        Event::Short('b', Some("x".to_string())), Event::Long("unknown".to_string(), None),
+                        Event::Positional("cmd".to_string()), Event::Positional("-a".to_string())],
+                   clp.events(&args).collect::<Vec<Event>>());
+    }
+}
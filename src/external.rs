@@ -0,0 +1,206 @@
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::io::{Error as IOError, ErrorKind, Result as IOResult};
+use std::path::{is_separator, Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+use crate::commands::program_basename;
+use crate::{CommandLineParser, ParseResult, EXTERNAL_COMMAND_NOT_FOUND_ERROR_KEY, MISSING_EXTERNAL_COMMAND_ERROR_KEY};
+
+// Environment variable with the external commands search path
+const PATH_ENV_VAR: &str = "PATH";
+
+// Separator between the program name and the external command name (i.g. git-foo)
+const EXTERNAL_COMMAND_SEPARATOR: &str = "-";
+
+// Function that checks if a path is an executable file
+// * `path` - file path
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|metadata| metadata.is_file() && 0 != metadata.permissions().mode() & 0o111)
+        .unwrap_or(false)
+}
+
+// Function that checks if a path is an executable file
+// * `path` - file path
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+impl CommandLineParser {
+    /// Method to enable external commands (i.g. git foo runs git-foo):
+    /// parse_commands reports the first argument that is not an option and
+    /// not a command name as an external command, with the arguments following it
+    /// (see ParseResult::get_external_command)
+    /// * `external_commands` - true to enable external commands
+    pub fn set_external_commands(&mut self, external_commands: bool) {
+        self.external_commands = external_commands;
+    }
+
+    /// Associated function to find the executable file of an external command
+    /// in a search path, the file name is the prefix followed by the command name
+    /// (i.g. tool-foo), None is returned if it is not found or the
+    /// command name is a path. Empty directories of the search path are skipped,
+    /// they are not the working directory
+    /// * `prefix` - executable file name prefix (i.g. tool-)
+    /// * `name` - external command name (i.g. foo)
+    /// * `search_path` - directories list, in the PATH environment variable format
+    pub fn find_external_command(prefix: &str, name: &str, search_path: &OsStr) -> Option<PathBuf> {
+        if name.is_empty() || name.contains(is_separator) {
+            return None;
+        }
+        let file_name = format!("{}{}{}", prefix, name, env::consts::EXE_SUFFIX);
+        env::split_paths(search_path)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map(|dir| dir.join(&file_name))
+            .find(|path| is_executable(path))
+    }
+}
+
+impl ParseResult {
+    /// Method to retrieve the external command found by parse_commands,
+    /// its name and the arguments following it as they are,
+    /// None if there is no external command
+    pub fn get_external_command(&self) -> Option<(&str, &Vec<String>)> {
+        self.external_command.as_ref().map(|(name, args)| (name.as_str(), args))
+    }
+
+    // Method that returns the command to run the external command
+    // found by parse_commands (see run_external_command)
+    // * `prefix` - executable file name prefix, None for the program name
+    //   followed by - (i.g. tool-)
+    // * `search_path` - directories list, None for the PATH environment variable
+    // * `env_search_path` - value of the PATH environment variable, None if it is not set
+    fn external_command(&self, prefix: Option<&str>, search_path: Option<&OsStr>, env_search_path: Option<OsString>) -> IOResult<Command> {
+        let Some((name, args)) = self.get_external_command() else {
+            return Err(IOError::new(ErrorKind::InvalidInput, self.errors_catalog.get_message(MISSING_EXTERNAL_COMMAND_ERROR_KEY, "", "")));
+        };
+        let prefix = match prefix {
+            Some(prefix) => prefix.to_string(),
+            None => format!("{}{}", program_basename(&self.program_name), EXTERNAL_COMMAND_SEPARATOR)
+        };
+        let path = match search_path {
+            Some(search_path) => CommandLineParser::find_external_command(&prefix, name, search_path),
+            None => env_search_path.and_then(|search_path| CommandLineParser::find_external_command(&prefix, name, &search_path))
+        };
+        match path {
+            Some(path) => {
+                let mut command = Command::new(path);
+                command.args(args);
+                Ok(command)
+            },
+            None => {
+                let file_name = format!("{}{}", prefix, name);
+                Err(IOError::new(ErrorKind::NotFound, format!("{}: {}", file_name,
+                    self.errors_catalog.get_message(EXTERNAL_COMMAND_NOT_FOUND_ERROR_KEY, &file_name, ""))))
+            }
+        }
+    }
+
+    /// Method to run the external command found by parse_commands
+    /// with its arguments as a child process and wait for it, the current
+    /// process goes on (see exec_external_command to replace it).
+    /// The executable file is found by CommandLineParser::find_external_command.
+    /// A NotFound error is returned if the executable file is not found
+    /// or the search path is not given and PATH is not set, an InvalidInput
+    /// error if there is no external command; their messages are read from
+    /// the errors catalog (external_command_not_found and missing_external_command keys)
+    /// * `prefix` - executable file name prefix, None for the program name
+    ///   followed by - (i.g. tool-)
+    /// * `search_path` - directories list, None for the PATH environment variable
+    pub fn run_external_command(&self, prefix: Option<&str>, search_path: Option<&OsStr>) -> IOResult<ExitStatus> {
+        self.external_command(prefix, search_path, env::var_os(PATH_ENV_VAR))?.status()
+    }
+
+    /// Method to replace the current process with the external command
+    /// found by parse_commands, as git does for its external commands;
+    /// the executable file is found as by run_external_command.
+    /// It returns only if the command cannot be run, with the error
+    /// * `prefix` - executable file name prefix, None for the program name
+    ///   followed by - (i.g. tool-)
+    /// * `search_path` - directories list, None for the PATH environment variable
+    #[cfg(unix)]
+    pub fn exec_external_command(&self, prefix: Option<&str>, search_path: Option<&OsStr>) -> IOError {
+        use std::os::unix::process::CommandExt;
+        match self.external_command(prefix, search_path, env::var_os(PATH_ENV_VAR)) {
+            Ok(mut command) => command.exec(),
+            Err(error) => error
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // Temporary directory removed when dropped, even if an assertion fails
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn external_command_result() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_short_boolean_option('v', false, "verbose output").unwrap();
+        clp.add_command("build", CommandLineParser::new(None)).unwrap();
+        clp.set_external_commands(true);
+        let args: Vec<String> = ["/usr/bin/tool", "-v", "foo", "-x", "build", "--", "a"].iter().map(|arg| arg.to_string()).collect();
+        let (result, commands) = clp.parse_commands(&args).unwrap();
+        assert!(commands.is_empty());
+        assert!(result.is_set(&verbose_option));
+        let raw_args: Vec<String> = ["-x", "build", "--", "a"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(Some(("foo", &raw_args)), result.get_external_command());
+        let args: Vec<String> = ["tool", "build"].iter().map(|arg| arg.to_string()).collect();
+        let (result, commands) = clp.parse_commands(&args).unwrap();
+        assert_eq!((None, 1), (result.get_external_command(), commands.len()));
+        assert_eq!(ErrorKind::InvalidInput, result.run_external_command(None, None).unwrap_err().kind());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_external_command() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir(env::temp_dir().join(format!("rsclp-external-{}", std::process::id())));
+        let dir = temp_dir.0.clone();
+        fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("tool-count");
+        fs::write(&script, "#!/bin/sh\nexit $#\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        fs::write(dir.join("tool-plain"), "").unwrap();
+
+        assert_eq!(Some(script.clone()), CommandLineParser::find_external_command("tool-", "count", dir.as_os_str()));
+        assert_eq!(None, CommandLineParser::find_external_command("tool-", "plain", dir.as_os_str()));
+        assert_eq!(None, CommandLineParser::find_external_command("", "tool-count/..", dir.as_os_str()));
+        assert_eq!(None, CommandLineParser::find_external_command("tool-", "count", OsStr::new("")));
+        let search_path = env::join_paths([PathBuf::new(), dir.clone()]).unwrap();
+        assert_eq!(Some(script), CommandLineParser::find_external_command("tool-", "count", &search_path));
+
+        let mut clp = CommandLineParser::new(None);
+        clp.set_external_commands(true);
+        let args: Vec<String> = ["/usr/bin/tool", "count", "a", "b"].iter().map(|arg| arg.to_string()).collect();
+        let (result, _) = clp.parse_commands(&args).unwrap();
+        assert_eq!(Some(2), result.run_external_command(None, Some(dir.as_os_str())).unwrap().code());
+        assert_eq!(ErrorKind::NotFound, result.external_command(None, None, None).unwrap_err().kind());
+        assert!(result.external_command(None, None, Some(dir.clone().into_os_string())).is_ok());
+        let args: Vec<String> = ["tool", "plain"].iter().map(|arg| arg.to_string()).collect();
+        let (result, _) = clp.parse_commands(&args).unwrap();
+        assert_eq!("tool-plain: external command not found",
+                   result.run_external_command(None, Some(dir.as_os_str())).unwrap_err().to_string());
+        let catalog = crate::ErrorCatalog::from_table(&[(EXTERNAL_COMMAND_NOT_FOUND_ERROR_KEY, "commande externe introuvable")]);
+        let mut clp = CommandLineParser::with_catalog(catalog);
+        clp.set_external_commands(true);
+        let (result, _) = clp.parse_commands(&args).unwrap();
+        assert_eq!("tool-plain: commande externe introuvable",
+                   result.run_external_command(None, Some(dir.as_os_str())).unwrap_err().to_string());
+    }
+}
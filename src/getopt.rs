@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::io::{Error as IOError, ErrorKind, Result as IOResult};
+
+use crate::{CommandLineParser, CommandLineParserError, ParsingMode};
+
+// Optstring leading characters: stop at the first non-option
+// and report errors silently (ignored, errors are always returned)
+const STOP_AT_NON_OPTION_TAG: char = '+';
+const SILENT_ERRORS_TAG: char = ':';
+
+// Optstring character following an option with argument
+const ARGUMENT_TAG: char = ':';
+
+// Argument text shown in the help text of the options with argument
+const GETOPT_ARG_TEXT: &str = "argument";
+
+/// Argument of an option created by CommandLineParser::from_getopt
+/// or CommandLineParser::from_getopt_long, as the getopt_long
+/// no_argument, required_argument and optional_argument values
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum OptionArgument {
+    NoArgument,
+    RequiredArgument,
+    OptionalArgument,
+}
+
+// Function that returns the InvalidInput error of a getopt definition
+// * `message` - error message
+fn getopt_error(message: &str) -> IOError {
+    IOError::new(ErrorKind::InvalidInput, message.to_string())
+}
+
+// Function that parses an optstring (i.g. "+ab:c::"),
+// it returns if parsing stops at the first non-option
+// and the single character options with their argument
+// * `optstring` - getopt options string
+fn parse_optstring(optstring: &str) -> IOResult<(bool, Vec<(char, OptionArgument)>)> {
+    let mut stop_at_non_option = false;
+    let mut chars = optstring.chars().peekable();
+    while let Some(tag) = chars.next_if(|tag| [STOP_AT_NON_OPTION_TAG, SILENT_ERRORS_TAG].contains(tag)) {
+        stop_at_non_option |= STOP_AT_NON_OPTION_TAG == tag;
+    }
+    let mut options: Vec<(char, OptionArgument)> = vec![];
+    while let Some(short_form_option) = chars.next() {
+        if short_form_option.is_whitespace() || ['-', ARGUMENT_TAG].contains(&short_form_option) {
+            return Err(getopt_error(&format!("'{}' is not a valid option character in '{}'", short_form_option, optstring)));
+        }
+        let mut argument = OptionArgument::NoArgument;
+        if chars.next_if_eq(&ARGUMENT_TAG).is_some() {
+            argument = OptionArgument::RequiredArgument;
+            if chars.next_if_eq(&ARGUMENT_TAG).is_some() {
+                argument = OptionArgument::OptionalArgument;
+            }
+        }
+        options.push((short_form_option, argument));
+    }
+    Ok((stop_at_non_option, options))
+}
+
+impl CommandLineParser {
+    /// Associated function to create a command line parser from a getopt
+    /// options string, it returns the parser and a map from each flag (i.g. -b)
+    /// to the option identifier. Each character is an option, followed by : if it
+    /// requires an argument or by :: if the argument is optional; options without
+    /// argument are boolean options, the others string options.
+    /// The parser follows the getopt rules: an argument can be attached
+    /// to its flag (i.g. -bvalue), an optional argument has to be attached
+    /// (without it the value is an empty text), unknown options are errors,
+    /// arguments after -- are positional arguments and, if the options string
+    /// starts with + (ParsingMode::PosixParsingMode) or POSIXLY_CORRECT is honored
+    /// (see set_posixly_correct) and set, parsing stops at the first non-option argument,
+    /// the following ones are positional arguments too
+    /// * `optstring` - getopt options string (i.g. "ab:c::")
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let (clp, options) = CommandLineParser::from_getopt("+ab:c::").unwrap();
+    ///     let args: Vec<String> = ["tool", "-abvalue", "-c", "cmd", "-a"].iter().map(|arg| arg.to_string()).collect();
+    ///     let result = clp.parse(&args).unwrap();
+    ///     assert_eq!("value", result.get_value::<String>(&options["-b"]).unwrap());
+    ///     assert_eq!("", result.get_value::<String>(&options["-c"]).unwrap());
+    ///     assert_eq!(&vec!["cmd".to_string(), "-a".to_string()], result.get_positional_args());
+    /// }
+    /// ```
+    pub fn from_getopt(optstring: &str) -> IOResult<(Self, HashMap<String, u64>)> {
+        Self::from_getopt_long(optstring, &[])
+    }
+
+    /// Associated function to create a command line parser from a getopt
+    /// options string (see from_getopt) and a getopt_long table of long options,
+    /// each one made of name, argument and the equivalent single character option
+    /// of the options string (None for a long only option).
+    /// The map returned with the parser has the long flags too (i.g. --config)
+    /// * `optstring` - getopt options string (i.g. "ab:c::")
+    /// * `long_options` - long options table
+    ///   (i.g. [("config", OptionArgument::RequiredArgument, Some('b'))])
+    pub fn from_getopt_long(optstring: &str, long_options: &[(&str, OptionArgument, Option<char>)]) -> IOResult<(Self, HashMap<String, u64>)> {
+        let (stop_at_non_option, short_options) = parse_optstring(optstring)?;
+        let mut clp = CommandLineParser::new(None);
+        if stop_at_non_option {
+            clp.set_parsing_mode(ParsingMode::PosixParsingMode);
+        } else {
+            clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        }
+        clp.getopt_compatible = true;
+        for (long_form_option, _, short_form_option) in long_options {
+            if let Some(short_form_option) = short_form_option {
+                if !short_options.iter().any(|(short, _)| short == short_form_option) {
+                    return Err(getopt_error(&format!("-{} of --{} is not in '{}'", short_form_option, long_form_option, optstring)));
+                }
+            }
+        }
+        let to_getopt_error = |error: CommandLineParserError| getopt_error(&error.to_string());
+        let mut options: HashMap<String, u64> = HashMap::new();
+        let mut add_option = |clp: &mut CommandLineParser, short: Option<char>, long: &str, argument: OptionArgument| -> IOResult<()> {
+            let added = match (argument, short) {
+                (OptionArgument::NoArgument, Some(short)) if long.is_empty() => clp.add_short_boolean_option(short, false, ""),
+                (OptionArgument::NoArgument, Some(short)) => clp.add_boolean_option(short, long, false, ""),
+                (OptionArgument::NoArgument, None) => clp.add_long_boolean_option(long, false, ""),
+                (_, Some(short)) if long.is_empty() => clp.add_short_string_option(short, false, GETOPT_ARG_TEXT, ""),
+                (_, Some(short)) => clp.add_string_option(short, long, false, GETOPT_ARG_TEXT, ""),
+                (_, None) => clp.add_long_string_option(long, false, GETOPT_ARG_TEXT, "")
+            };
+            let option_hash = added.map_err(to_getopt_error)?;
+            if OptionArgument::OptionalArgument == argument {
+                clp.set_option_optional_argument(&option_hash, true).map_err(to_getopt_error)?;
+            }
+            if let Some(short) = short {
+                options.insert(format!("-{}", short), option_hash);
+            }
+            if !long.is_empty() {
+                options.insert(format!("--{}", long), option_hash);
+            }
+            Ok(())
+        };
+        for (short_form_option, argument) in &short_options {
+            let long = long_options.iter().find(|(_, _, short)| Some(*short_form_option) == *short);
+            match long {
+                Some((long_form_option, long_argument, _)) if long_argument != argument => {
+                    return Err(getopt_error(&format!("--{} and -{} arguments differ", long_form_option, short_form_option)));
+                },
+                Some((long_form_option, _, _)) => add_option(&mut clp, Some(*short_form_option), long_form_option, *argument)?,
+                None => add_option(&mut clp, Some(*short_form_option), "", *argument)?
+            }
+        }
+        for (long_form_option, argument, short_form_option) in long_options {
+            if short_form_option.is_none() {
+                add_option(&mut clp, None, long_form_option, *argument)?;
+            }
+        }
+        Ok((clp, options))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optstring_parsing() {
+        let (stop_at_non_option, options) = parse_optstring("+:ab:c::").unwrap();
+        assert!(stop_at_non_option);
+        assert_eq!(vec![('a', OptionArgument::NoArgument), ('b', OptionArgument::RequiredArgument),
+                        ('c', OptionArgument::OptionalArgument)], options);
+        assert_eq!("'-' is not a valid option character in 'a-'", parse_optstring("a-").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn getopt_long_parser() {
+        let (clp, options) = CommandLineParser::from_getopt_long("ab:c::", &[
+            ("all", OptionArgument::NoArgument, Some('a')),
+            ("color", OptionArgument::OptionalArgument, None),
+            ("output", OptionArgument::RequiredArgument, None)
+        ]).unwrap();
+        assert_eq!(options["-a"], options["--all"]);
+        assert!(ParsingMode::PositionalArgumentsMode == clp.get_parsing_mode());
+        let args: Vec<String> = ["tool", "file1", "-b", "x", "--color", "--output", "out.txt", "-cfull", "file2", "--", "-a"]
+                                    .iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert!(!result.is_set(&options["--all"]));
+        assert_eq!("x", result.get_value::<String>(&options["-b"]).unwrap());
+        assert_eq!("", result.get_value::<String>(&options["--color"]).unwrap());
+        assert_eq!("out.txt", result.get_value::<String>(&options["--output"]).unwrap());
+        assert_eq!("full", result.get_value::<String>(&options["-c"]).unwrap());
+        assert_eq!(&vec!["file1".to_string(), "file2".to_string()], result.get_remaining_args());
+        assert_eq!(&vec!["-a".to_string()], result.get_positional_args());
+        let args: Vec<String> = ["tool", "-ax"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!("UndefinedCommandLineOptionType -x: option not found", clp.parse(&args).unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,268 @@
+// Minimal JSON writer and reader used to export and import the command line
+// definition, objects keep the insertion order of their members so the output is stable
+
+use std::iter::Peekable;
+use std::result::Result as StdResult;
+use std::str::Chars;
+
+// Indentation used for each nesting level
+const JSON_INDENT: &str = "  ";
+
+// JSON value
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(u64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    // Associated function to create a JSON object
+    // * `members` - members names and values in the output order
+    pub(crate) fn object(members: Vec<(&str, JsonValue)>) -> Self {
+        JsonValue::Object(members.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+    }
+
+    // Associated function to create a JSON string,
+    // None is written as null
+    // * `text` - optional text
+    pub(crate) fn optional_string(text: Option<&str>) -> Self {
+        text.map_or(JsonValue::Null, |text| JsonValue::String(text.to_string()))
+    }
+
+    // Method that returns the JSON text of the value,
+    // arrays and objects are written one element per line
+    // * `level` - nesting level of the value
+    pub(crate) fn render(&self, level: usize) -> String {
+        match self {
+            JsonValue::Null => "null".to_string(),
+            JsonValue::Bool(value) => value.to_string(),
+            JsonValue::Number(value) => value.to_string(),
+            JsonValue::String(text) => escape(text),
+            JsonValue::Array(elements) => {
+                if elements.is_empty() {
+                    return "[]".to_string();
+                }
+                let elements: Vec<String> = elements.iter()
+                                                .map(|element| format!("{}{}", JSON_INDENT.repeat(level + 1), element.render(level + 1)))
+                                                .collect();
+                format!("[\n{}\n{}]", elements.join(",\n"), JSON_INDENT.repeat(level))
+            },
+            JsonValue::Object(members) => {
+                if members.is_empty() {
+                    return "{}".to_string();
+                }
+                let members: Vec<String> = members.iter()
+                                                .map(|(name, value)| format!("{}{}: {}", JSON_INDENT.repeat(level + 1), escape(name), value.render(level + 1)))
+                                                .collect();
+                format!("{{\n{}\n{}}}", members.join(",\n"), JSON_INDENT.repeat(level))
+            }
+        }
+    }
+}
+
+// Function that reads a JSON text, numbers have to be non negative integers.
+// It returns the value or the line number and the message of the first error
+// * `text` - JSON text
+pub(crate) fn parse(text: &str) -> StdResult<JsonValue, (usize, String)> {
+    let mut reader = JsonReader { chars: text.chars().peekable(), line: 1 };
+    let value = reader.read_value()?;
+    reader.skip_blanks();
+    match reader.chars.next() {
+        Some(character) => Err(reader.error(&format!("unexpected '{}' after the value", character))),
+        None => Ok(value)
+    }
+}
+
+// Reader of a JSON text that keeps the current line number
+struct JsonReader<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl JsonReader<'_> {
+    // Method that returns an error at the current line
+    // * `message` - error message
+    fn error(&self, message: &str) -> (usize, String) {
+        (self.line, message.to_string())
+    }
+
+    // Method to skip blanks and new lines
+    fn skip_blanks(&mut self) {
+        while let Some(character) = self.chars.next_if(|character| character.is_whitespace()) {
+            if '\n' == character {
+                self.line += 1;
+            }
+        }
+    }
+
+    // Method to read the expected character after blanks
+    // * `expected` - expected character
+    fn expect(&mut self, expected: char) -> StdResult<(), (usize, String)> {
+        self.skip_blanks();
+        match self.chars.next() {
+            Some(character) if expected == character => Ok(()),
+            Some(character) => Err(self.error(&format!("expected '{}' instead of '{}'", expected, character))),
+            None => Err(self.error(&format!("expected '{}' at the end of the text", expected)))
+        }
+    }
+
+    // Method to read a value after blanks
+    fn read_value(&mut self) -> StdResult<JsonValue, (usize, String)> {
+        self.skip_blanks();
+        match self.chars.peek().copied() {
+            Some('{') => self.read_object(),
+            Some('[') => self.read_array(),
+            Some('"') => Ok(JsonValue::String(self.read_string()?)),
+            Some(character) if character.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some(digit) = self.chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                digits.parse::<u64>().map(JsonValue::Number).map_err(|_| self.error(&format!("number {} is too big", digits)))
+            },
+            Some(character) if character.is_ascii_alphabetic() => {
+                let mut word = String::new();
+                while let Some(letter) = self.chars.next_if(char::is_ascii_alphabetic) {
+                    word.push(letter);
+                }
+                match word.as_str() {
+                    "null" => Ok(JsonValue::Null),
+                    "true" => Ok(JsonValue::Bool(true)),
+                    "false" => Ok(JsonValue::Bool(false)),
+                    _ => Err(self.error(&format!("unknown value '{}'", word)))
+                }
+            },
+            Some(character) => Err(self.error(&format!("unexpected '{}'", character))),
+            None => Err(self.error("missing value at the end of the text"))
+        }
+    }
+
+    // Method to read a string, the opening quote included
+    fn read_string(&mut self) -> StdResult<String, (usize, String)> {
+        self.expect('"')?;
+        let mut result = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(result),
+                Some('\\') => match self.chars.next() {
+                    Some('n') => result.push('\n'),
+                    Some('r') => result.push('\r'),
+                    Some('t') => result.push('\t'),
+                    Some('b') => result.push('\u{8}'),
+                    Some('f') => result.push('\u{c}'),
+                    Some('u') => {
+                        let code: String = (0..4).filter_map(|_| self.chars.next()).collect();
+                        let character = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                                            .ok_or_else(|| self.error(&format!("invalid escape sequence \\u{}", code)))?;
+                        result.push(character);
+                    },
+                    Some(character @ ('"' | '\\' | '/')) => result.push(character),
+                    Some(character) => return Err(self.error(&format!("invalid escape sequence \\{}", character))),
+                    None => return Err(self.error("unterminated string"))
+                },
+                Some('\n') | None => return Err(self.error("unterminated string")),
+                Some(character) => result.push(character)
+            }
+        }
+    }
+
+    // Method to read an array, the opening bracket included
+    fn read_array(&mut self) -> StdResult<JsonValue, (usize, String)> {
+        self.expect('[')?;
+        let mut elements: Vec<JsonValue> = vec![];
+        self.skip_blanks();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(JsonValue::Array(elements));
+        }
+        loop {
+            elements.push(self.read_value()?);
+            self.skip_blanks();
+            if self.chars.next_if_eq(&']').is_some() {
+                return Ok(JsonValue::Array(elements));
+            }
+            self.expect(',')?;
+        }
+    }
+
+    // Method to read an object, the opening brace included
+    fn read_object(&mut self) -> StdResult<JsonValue, (usize, String)> {
+        self.expect('{')?;
+        let mut members: Vec<(String, JsonValue)> = vec![];
+        self.skip_blanks();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_blanks();
+            let name = self.read_string()?;
+            if members.iter().any(|(member_name, _)| *member_name == name) {
+                return Err(self.error(&format!("duplicate member '{}'", name)));
+            }
+            self.expect(':')?;
+            members.push((name, self.read_value()?));
+            self.skip_blanks();
+            if self.chars.next_if_eq(&'}').is_some() {
+                return Ok(JsonValue::Object(members));
+            }
+            self.expect(',')?;
+        }
+    }
+}
+
+// Function that returns the JSON string of a text,
+// quotes, backslashes and control characters are escaped
+// * `text` - text to write
+fn escape(text: &str) -> String {
+    let mut result = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            character if character.is_control() => result.push_str(&format!("\\u{:04x}", character as u32)),
+            character => result.push(character)
+        }
+    }
+    result.push('"');
+    result
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_text() {
+        assert_eq!("\"a \\\"b\\\" \\\\ c\\n\\u001b\"", escape("a \"b\" \\ c\n\u{1b}"));
+    }
+
+    #[test]
+    fn render_values() {
+        let value = JsonValue::object(vec![
+            ("name", JsonValue::String("app".to_string())),
+            ("flags", JsonValue::Array(vec![JsonValue::Bool(true), JsonValue::Null])),
+            ("empty", JsonValue::Array(vec![])),
+            ("count", JsonValue::Number(2)),
+        ]);
+        assert_eq!("{\n  \"name\": \"app\",\n  \"flags\": [\n    true,\n    null\n  ],\n  \"empty\": [],\n  \"count\": 2\n}",
+                   value.render(0));
+    }
+
+    #[test]
+    fn parse_values() {
+        let text = "{\n  \"name\": \"a \\\"b\\\" \\u00e8\",\n  \"list\": [true, null, 12, []],\n  \"empty\": {}\n}";
+        let value = parse(text).unwrap();
+        assert_eq!(text.replace("\\u00e8", "\u{e8}").replace("[true, null, 12, []]", "[\n    true,\n    null,\n    12,\n    []\n  ]"),
+                   value.render(0));
+        assert_eq!(Err((2, "expected ',' instead of '\"'".to_string())), parse("{\"a\": 1\n\"b\": 2}").map(|_| ()));
+        assert_eq!(Err((1, "unterminated string".to_string())), parse("[\"a").map(|_| ()));
+        assert_eq!(Err((1, "unexpected 'x' after the value".to_string())), parse("1 x").map(|_| ()));
+        assert_eq!(Err((1, "duplicate member 'a'".to_string())), parse("{\"a\": 1, \"a\": 2}").map(|_| ()));
+    }
+}
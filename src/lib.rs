@@ -1,16 +1,39 @@
 use std::any::{Any, TypeId};
 use std::env::{Args, ArgsOs};
 use std::fmt::{Debug, Display, Result};
-use std::io::Write;
+use std::io::{BufRead, IsTerminal, Write};
 use std::io::Result as IOResult;
+use std::ops::Range;
 use std::result::Result as StdResult;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::str::FromStr;
 use std::string::ToString;
+use json::JsonValue;
+use text::{display_width, paint, wrap_text, ANSI_ARG_TEXT_STYLE, ANSI_ERROR_STYLE, ANSI_FLAGS_STYLE, ANSI_HEADING_STYLE};
+
+mod catalog;
+mod commands;
+mod docopt;
+mod events;
+mod external;
+mod getopt;
+mod json;
+mod sections;
+mod shell;
+mod spec;
+mod text;
+pub use catalog::{ErrorCatalog, ERROR_KEYS, DEPRECATED_OPTION_WARNING_KEY, DEPRECATED_OPTION_REPLACEMENT_WARNING_KEY, COMMAND_ALREADY_EXISTS_ERROR_KEY, COMMAND_NOT_FOUND_ERROR_KEY, MISSING_COMMAND_ERROR_KEY,
+                  MISSING_EXTERNAL_COMMAND_ERROR_KEY, EXTERNAL_COMMAND_NOT_FOUND_ERROR_KEY,
+                  UNTERMINATED_QUOTE_ERROR_KEY, MISSING_ESCAPED_CHARACTER_ERROR_KEY, HEADING_KEY_PREFIX};
+pub use events::{Event, Events};
+use events::ArgumentKind;
+pub use getopt::OptionArgument;
+pub use sections::{OptionScope, Section};
 
 /// Error message indexes
 /// if you want to set error message in your language follow the indexes order
-/// see CommandLineParser::new associated function example
+/// see CommandLineParser::new associated function example,
+/// ErrorCatalog messages are identified by the keys in ERROR_KEYS instead
 pub const OPTION_ALREADY_EXISTS_ERROR_IDX: usize = 0usize;
 pub const SHORT_OPTION_ALREADY_EXISTS_ERROR_IDX: usize = OPTION_ALREADY_EXISTS_ERROR_IDX + 1usize;
 pub const LONG_OPTION_ALREADY_EXISTS_ERROR_IDX: usize = SHORT_OPTION_ALREADY_EXISTS_ERROR_IDX + 1usize;
@@ -27,23 +50,6 @@ pub const OPTION_IDENTIFIER_NOT_FOUND_ERROR_IDX: usize = MANDATORY_OPTION_HAS_NO
 pub const OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX: usize = OPTION_IDENTIFIER_NOT_FOUND_ERROR_IDX + 1usize;
 pub const CMD_LINE_OPTION_ERROR_NUM: usize = OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX + 1usize;
 
-// Error message in the Englih default language
-const OPTION_ALREADY_EXISTS_ERROR: &str = "option already exists";
-const SHORT_OPTION_ALREADY_EXISTS_ERROR: &str = "single character option already exists";
-const LONG_OPTION_ALREADY_EXISTS_ERROR: &str = "long form option already exists";
-const OPTION_IS_NOT_SET_ERROR: &str = "is not set";
-const OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR: &str ="is not of the required type";
-const OPTION_NOT_FOUND_ERROR: &str = "option not found";
-const OPTION_TYPE_UNDEFINED_ERROR: &str = "Undefined command line option";
-const BOOLEAN_OPTION_MISMATCH_VALUE_SET_ERROR: &str = "cannot set a boolean value to this option type";
-const INTEGER_OPTION_MISMATCH_VALUE_SET_ERROR: &str = "cannot set an integer value to this option type";
-const FPOINT_OPTION_MISMATCH_VALUE_SET_ERROR: &str = "cannot set a floating point value to this option type";
-const MISSING_OPTION_ARGUMENT_ERROR: & str = "missing option argument"; 
-const MANDATORY_OPTION_HAS_NOT_SET_ERROR: &str = "mandatory option has not been set";
-const OPTION_IDENTIFIER_NOT_FOUND_ERROR: &str = "option identifier not found";
-const OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR: &str =  "option argument already assigned";
-
-
 // Short form not set value
 const EMPTY_SHORT_FORM: char = ' ';
 
@@ -57,7 +63,39 @@ const COMMAND_LINE_OPTION_TYPE_STRING: &str = "StringCommandLineOptionType";
 // Command line option assign tas
 const OPTION_ASSIGN_TAG: &str = "=";
 
+// Help text layout: tab width used to indent options,
+// default width used when COLUMNS is not set and
+// minimum width of the description column
+const HELP_TAB_WIDTH: usize = 8;
+const DEFAULT_HELP_WIDTH: usize = 80;
+const MIN_HELP_DESCRIPTION_WIDTH: usize = 20;
+
+// Environment variable that selects the POSIX parsing mode
+const POSIXLY_CORRECT_ENV_VAR: &str = "POSIXLY_CORRECT";
+
+// Environment variables used to detect if the output is colored
+const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+const CLICOLOR_FORCE_ENV_VAR: &str = "CLICOLOR_FORCE";
+
+// Error render command line indentation
+const RENDER_INDENT: &str = "    ";
+
+// Interactive prompt texts
+const PROMPT_BOOLEAN_ARG_TEXT: &str = "true/false";
+const PROMPT_TAG: &str = ": ";
+
+// Version of the JSON export schema (see CommandLineParser::to_json)
+pub const JSON_SCHEMA_VERSION: u64 = 1;
+
+// Help text usage line and sections texts
+const USAGE_OPTIONS_TEXT: &str = "[OPTIONS]";
+const USAGE_POSITIONAL_ARGS_TEXT: &str = "[-- <arguments>...]";
+const EXAMPLES_HEADING: &str = "Examples";
+const OPTIONS_HEADING: &str = "Options";
+
 // Comman line option type enumeration
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 enum CommandLineOptionType {
     UNDEFINED(&'static str),
     BOOLEAN(&'static str),
@@ -78,11 +116,11 @@ impl CommandLineOptionType {
     // of command line option type
     fn unwrap(&self) -> &'static str {
         match self {
-            Self::UNDEFINED(value) => { *value }
-            Self::BOOLEAN(value) => { *value }
-            Self::INTEGER(value) => { *value }
-            Self::FPOINT(value) => { *value }
-            Self::STRING(value) => { *value }
+            Self::UNDEFINED(value) => { value }
+            Self::BOOLEAN(value) => { value }
+            Self::INTEGER(value) => { value }
+            Self::FPOINT(value) => { value }
+            Self::STRING(value) => { value }
         }
     }
 }
@@ -172,7 +210,9 @@ impl PartialEq for CommandLineOptionType {
 
 // Command line option struct
 // used by CommandLineParser to store
-// command line options
+// command line options definition, values are
+// filled only in the copy owned by a ParseResult
+#[derive(Clone)]
 struct CommandLineOption {
     short_form_option: char,
     long_form_option: String,
@@ -180,7 +220,18 @@ struct CommandLineOption {
     arg_text: String,
     help_text: String,
     values: Vec<String>,
+    value_indexes: Vec<usize>,
     typ: CommandLineOptionType,
+    hidden: bool,
+    deprecated: Option<String>,
+    short_aliases: Vec<char>,
+    long_aliases: Vec<String>,
+    secret: bool,
+    category: String,
+    long_help: String,
+    optional_argument: bool,
+    scope: OptionScope,
+    section_marker: bool,
 }
 
 // CommandLineOption implemenetation
@@ -199,7 +250,18 @@ impl CommandLineOption {
             arg_text: String::from(arg_text),
             help_text: help_text.to_string(),
             values: vec![],
-            typ: CommandLineOptionType::new()
+            value_indexes: vec![],
+            typ: CommandLineOptionType::new(),
+            hidden: false,
+            deprecated: None,
+            short_aliases: vec![],
+            long_aliases: vec![],
+            secret: false,
+            category: String::new(),
+            long_help: String::new(),
+            optional_argument: false,
+            scope: OptionScope::GlobalScope,
+            section_marker: false
         }
     }
 
@@ -217,7 +279,18 @@ impl CommandLineOption {
             arg_text: String::from(arg_text),
             help_text: help_text.to_string(),
             values: vec![],
-            typ: CommandLineOptionType::new()
+            value_indexes: vec![],
+            typ: CommandLineOptionType::new(),
+            hidden: false,
+            deprecated: None,
+            short_aliases: vec![],
+            long_aliases: vec![],
+            secret: false,
+            category: String::new(),
+            long_help: String::new(),
+            optional_argument: false,
+            scope: OptionScope::GlobalScope,
+            section_marker: false
         }
     }
 
@@ -236,7 +309,18 @@ impl CommandLineOption {
             arg_text: String::from(arg_text),
             help_text: help_text.to_string(),
             values: vec![],
-            typ: CommandLineOptionType::new()
+            value_indexes: vec![],
+            typ: CommandLineOptionType::new(),
+            hidden: false,
+            deprecated: None,
+            short_aliases: vec![],
+            long_aliases: vec![],
+            secret: false,
+            category: String::new(),
+            long_help: String::new(),
+            optional_argument: false,
+            scope: OptionScope::GlobalScope,
+            section_marker: false
         }
     }
 
@@ -265,6 +349,47 @@ impl CommandLineOption {
         flags_repr
     }
 
+    // Method to retrive the string representation of the
+    // command line option flags shown in the help text,
+    // aliases are listed after the flag of the same form
+    // i.g -o/--out-dir/--output-directory
+    // * `show_aliases` - true to list aliases too
+    fn get_help_flags(&self, show_aliases: bool) -> String {
+        if !show_aliases {
+            return self.get_flags();
+        }
+        let mut flags: Vec<String> = vec![];
+        if EMPTY_SHORT_FORM != self.short_form_option {
+            flags.push(format!("-{}", self.short_form_option));
+        }
+        for alias in &self.short_aliases {
+            flags.push(format!("-{}", alias));
+        }
+        if !self.long_form_option.is_empty() {
+            flags.push(format!("--{}", self.long_form_option));
+        }
+        for alias in &self.long_aliases {
+            flags.push(format!("--{}", alias));
+        }
+        flags.join("/")
+    }
+
+    // Method that returns if the single character
+    // flag or one of its aliases is equal to the passed one
+    // * `short_form_option` - single character flag to check
+    fn has_short_form(&self, short_form_option: char) -> bool {
+        EMPTY_SHORT_FORM != short_form_option &&
+            (self.short_form_option == short_form_option || self.short_aliases.contains(&short_form_option))
+    }
+
+    // Method that returns if the long text
+    // flag or one of its aliases is equal to the passed one
+    // * `long_form_option` - long text flag to check
+    fn has_long_form(&self, long_form_option: &str) -> bool {
+        !long_form_option.is_empty() &&
+            (self.long_form_option == long_form_option || self.long_aliases.iter().any(|alias| alias == long_form_option))
+    }
+
     // Method to retrive command line option type
     // as a text
     fn get_type_name(&self) -> String {
@@ -274,60 +399,88 @@ impl CommandLineOption {
     // Method to retrive command line option help text
     // argument passed to this method are used
     // to allign the help text
+    // Lengths are measured in terminal columns, the description
+    // is wrapped and continuation lines are indented under the description column
     // * `max_flags_len` - longest flags text calculated by commnad line parser
     // * `max_arg_text_len` - longest agument text calculate by command line parser
-    fn help_text(&self, max_flags_len: usize, max_arg_text_len: usize) -> String {
-        let mut arg_text_len = self.arg_text.len();
-        
-        if 0 != arg_text_len {
-            let fill_len = max_arg_text_len - arg_text_len;
-            let fill = std::iter::repeat(" ").take(fill_len).collect::<String>();
-            format!("{:>max_flags_len$} <{:arg_text_len$}>{} {}.\n", 
-                self.get_flags(), self.arg_text, fill, self.help_text)
+    // * `show_aliases` - true to list aliases in the flags text
+    // * `description_width` - maximum width of the description column, 0 means no wrapping
+    // * `colored` - true to color flags and argument text with ANSI escape sequences
+    fn help_text(&self, max_flags_len: usize, max_arg_text_len: usize, show_aliases: bool, description_width: usize, colored: bool) -> String {
+        let flags = self.get_help_flags(show_aliases);
+        let flags_fill = " ".repeat(max_flags_len.saturating_sub(display_width(&flags)));
+        let arg_text = if self.arg_text.is_empty() {
+            " ".repeat(max_arg_text_len + 2)
         } else {
-            arg_text_len = max_arg_text_len + 2;
-            let arg_text = std::iter::repeat(" ").take(arg_text_len).collect::<String>();
-            format!("{:>max_flags_len$} {:arg_text_len$} {}.\n", 
-                self.get_flags(), arg_text, self.help_text)
+            format!("{}{}", paint(&format!("<{}>", self.arg_text), ANSI_ARG_TEXT_STYLE, colored),
+                    " ".repeat(max_arg_text_len.saturating_sub(display_width(&self.arg_text))))
+        };
+        let lines = wrap_text(&format!("{}.", self.help_text), description_width);
+        let mut result = format!("{}{} {} {}\n", flags_fill, paint(&flags, ANSI_FLAGS_STYLE, colored), arg_text, lines[0]);
+        let indent = " ".repeat(max_flags_len + max_arg_text_len + 4);
+        for line in &lines[1..] {
+            result.push_str(&format!("\t{}{}\n", indent, line));
+        }
+        result
+    }
+
+    // Method to retrive command line option long help text:
+    // flags and argument text on the first line followed by
+    // the long help (or the help text if it is not set) indented and wrapped
+    // * `show_aliases` - true to list aliases in the flags text
+    // * `description_width` - maximum width of the description, 0 means no wrapping
+    // * `colored` - true to color flags and argument text with ANSI escape sequences
+    fn long_help_text(&self, show_aliases: bool, description_width: usize, colored: bool) -> String {
+        let mut result = paint(&self.get_help_flags(show_aliases), ANSI_FLAGS_STYLE, colored);
+        if !self.arg_text.is_empty() {
+            result.push_str(&format!(" {}", paint(&format!("<{}>", self.arg_text), ANSI_ARG_TEXT_STYLE, colored)));
+        }
+        result.push('\n');
+        let short_help = format!("{}.", self.help_text);
+        let long_help = if self.long_help.is_empty() { short_help.as_str() } else { self.long_help.as_str() };
+        for paragraph in long_help.lines() {
+            for line in wrap_text(paragraph, description_width) {
+                if line.is_empty() {
+                    result.push('\n');
+                } else {
+                    result.push_str(&format!("\t{}{}\n", RENDER_INDENT, line));
+                }
+            }
         }
+        result
     }
 
     // Method that returns if a command line option
     // has be set during the parsing phase
     fn is_set(&self) -> bool {
-        0 != self.values.len()
+        !self.values.is_empty()
     }
 
     // Method taht return the first of all set values
     // ore None if no values has been set 
     fn get_value(&self) -> Option<&String> {
-        if self.values.len() > 0 {
-            return self.values.get(0)
-        }
-
-        None
+        self.values.first()
     }
 
     // Method to add a value to a command line option
     // It returns Ok(()) in case passed value respect option type
     // otherwhise it returns the command line option error index
     // * `value` - Value to be added
-    fn add_value(&mut self, value: &str) -> StdResult<(), usize> {
+    // * `value_index` - index of the process argument the value comes from
+    fn add_value(&mut self, value: &str, value_index: usize) -> StdResult<(), usize> {
         let type_name = self.typ.unwrap();
         if type_name != COMMAND_LINE_OPTION_TYPE_UNDEFINED {
             if type_name != COMMAND_LINE_OPTION_TYPE_STRING {
-                if let Ok(_) = value.parse::<bool>() {
+                if value.parse::<bool>().is_ok() {
                     if type_name != COMMAND_LINE_OPTION_TYPE_BOOLEAN {
                         return Err(BOOLEAN_OPTION_MISMATCH_VALUE_SET_ERROR_IDX);    
                     }
-                }  else if let Ok(_) = value.parse::<i128>() {
+                }  else if value.parse::<i128>().is_ok() {
                     if type_name != COMMAND_LINE_OPTION_TYPE_INTEGER {
                         return Err(INTEGER_OPTION_MISMATCH_VALUE_SET_ERROR_IDX);
                     }
-                } else if let Ok(_) = value.parse::<f64>() {
-                    if type_name != COMMAND_LINE_OPTION_TYPE_FPOINT {
-                        return Err(FPOINT_OPTION_MISMATCH_VALUE_SET_ERROR_IDX);
-                    }
+                } else if value.parse::<f64>().is_ok() && type_name != COMMAND_LINE_OPTION_TYPE_FPOINT {
+                    return Err(FPOINT_OPTION_MISMATCH_VALUE_SET_ERROR_IDX);
                 }
             }
         } else {
@@ -336,11 +489,69 @@ impl CommandLineOption {
 
 
         self.values.push(value.to_string());
+        self.value_indexes.push(value_index);
+        Ok(())
+    }
+
+    // Method to check that a value can be converted
+    // to the command line option type, stricter than add_value
+    // it is used to validate values typed by the user
+    // It returns Ok(()) in case passed value respect option type
+    // otherwhise it returns the command line option error index
+    // * `value` - Value to be checked
+    fn check_value(&self, value: &str) -> StdResult<(), usize> {
+        let valid = match self.typ.unwrap() {
+            COMMAND_LINE_OPTION_TYPE_BOOLEAN => value.parse::<bool>().is_ok(),
+            COMMAND_LINE_OPTION_TYPE_INTEGER => value.parse::<i128>().is_ok(),
+            COMMAND_LINE_OPTION_TYPE_FPOINT => value.parse::<f64>().is_ok(),
+            COMMAND_LINE_OPTION_TYPE_STRING => !value.is_empty(),
+            _ => return Err(OPTION_TYPE_UNDEFINED_ERROR_IDX)
+        };
+        if !valid {
+            return Err(OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX);
+        }
         Ok(())
     }
+
+    // Method to retrive the text used to ask
+    // the option value to the user
+    // i.g -c/--config configuration file path <file path>:
+    fn prompt_text(&self) -> String {
+        let arg_text = if self.typ.unwrap() == COMMAND_LINE_OPTION_TYPE_BOOLEAN {
+            PROMPT_BOOLEAN_ARG_TEXT
+        } else {
+            self.arg_text.as_str()
+        };
+        if arg_text.is_empty() {
+            format!("{} {}{}", self.get_flags(), self.help_text, PROMPT_TAG)
+        } else {
+            format!("{} {} <{}>{}", self.get_flags(), self.help_text, arg_text, PROMPT_TAG)
+        }
+    }
+
+    // Method that returns all set values
     fn get_values(&self) -> &Vec<String> {
        &self.values
     }
+
+    // Method that returns the warning to be recorded
+    // when a deprecated option is found during the parsing phase,
+    // None is returned if the option is not deprecated
+    // * `errors_catalog` - catalog of the warning messages
+    fn deprecation_warning(&self, errors_catalog: &ErrorCatalog) -> Option<String> {
+        let flags = self.get_flags();
+        match &self.deprecated {
+            Some(replacement) if !replacement.is_empty() => {
+                Some(format!("{}: {}", flags,
+                    errors_catalog.get_message(DEPRECATED_OPTION_REPLACEMENT_WARNING_KEY, &flags, replacement)))
+            },
+            Some(_) => {
+                Some(format!("{}: {}", flags,
+                    errors_catalog.get_message(DEPRECATED_OPTION_WARNING_KEY, &flags, "")))
+            },
+            None => None
+        }
+    }
 }
 
 
@@ -383,8 +594,8 @@ impl PartialEq for CommandLineOption {
 ///     let mut clp = CommandLineParser::new(None);
 ///     let config_option = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
 ///     match clp.parse_args(std::env::args()) {
-///         Ok(()) => {
-///             let config_file: String = clp.get_value(&config_option).unwrap_or_else(|e| {
+///         Ok(result) => {
+///             let config_file: String = result.get_value(&config_option).unwrap_or_else(|e| {
 ///                 eprintln!("{}", e);
 ///                 String::from("") });
 ///             println!("config_file: {}", config_file);
@@ -400,7 +611,106 @@ impl PartialEq for CommandLineOption {
 pub struct CommandLineParserError {
     flags: String,
     typ: String,
-    error: String
+    error: String,
+    arg_index: Option<usize>,
+    char_range: Option<Range<usize>>,
+    // true if char_range is a range of a command line text (see split_command_line)
+    in_text: bool
+}
+
+impl CommandLineParserError {
+    // Associated function to create an error not related to a process argument
+    // * `flags` - flags of the command line option that caused the error
+    // * `typ` - type name of the command line option
+    // * `error` - error message
+    pub(crate) fn new(flags: String, typ: String, error: String) -> Self {
+        CommandLineParserError { flags, typ, error, arg_index: None, char_range: None, in_text: false }
+    }
+
+    // Associated function to create an error related to a process argument
+    // * `flags` - flags of the command line option that caused the error
+    // * `typ` - type name of the command line option
+    // * `error` - error message
+    // * `arg_index` - index of the process argument (0 is the program name)
+    // * `char_range` - range of characters of the process argument
+    pub(crate) fn new_at(flags: String, typ: String, error: String, arg_index: usize, char_range: Range<usize>) -> Self {
+        CommandLineParserError { flags, typ, error, arg_index: Some(arg_index), char_range: Some(char_range), in_text: false }
+    }
+
+    /// Method that returns the index of the process argument
+    /// that caused the error (0 is the program name),
+    /// None is returned if the error is not related to an argument
+    pub fn get_arg_index(&self) -> Option<usize> {
+        self.arg_index
+    }
+
+    /// Method that returns the range of characters
+    /// of the process argument that caused the error
+    /// (i.g. the f of -xvzf or the value of --ratio=abc),
+    /// None is returned if the error is not related to an argument
+    pub fn get_char_range(&self) -> Option<Range<usize>> {
+        self.char_range.clone().filter(|_| !self.in_text)
+    }
+
+    /// Method that returns the range of characters of the command line text
+    /// that caused the error (i.g. the unterminated quote of echo 'it),
+    /// None is returned if the error is not related to a command line text
+    /// (see CommandLineParser::split_command_line)
+    pub fn get_text_range(&self) -> Option<Range<usize>> {
+        self.char_range.clone().filter(|_| self.in_text)
+    }
+
+    /// Method to render the error followed by the command line
+    /// and a caret underline under the part of the argument that caused the error
+    /// (i.g.
+    /// IntegerCommandLineOptionType -V/--verbose: missing option argument
+    ///     app -cV
+    ///           ^ ),
+    /// for a command line text error the caret underlines the line
+    /// of the text (passed as the only process argument) that caused the error
+    /// * `process_args` - the parsed process arguments
+    pub fn render(&self, process_args: &[String]) -> String {
+        self.render_with(process_args, false)
+    }
+
+    /// Method to render the error as the render method
+    /// with the error heading and the caret underline colored
+    /// using ANSI escape sequences
+    /// * `process_args` - the parsed process arguments
+    pub fn render_colored(&self, process_args: &[String]) -> String {
+        self.render_with(process_args, true)
+    }
+
+    // Method that realizes render and render_colored
+    // * `process_args` - the parsed process arguments
+    // * `colored` - true to color the error heading and the caret underline
+    fn render_with(&self, process_args: &[String], colored: bool) -> String {
+        let heading = format!("{} {}:", self.typ, self.flags);
+        let mut result = format!("{} {}\n", paint(&heading, ANSI_ERROR_STYLE, colored), self.error);
+        let width_of = |chars: &[char]| display_width(&chars.iter().collect::<String>());
+        if let Some(text_range) = self.char_range.as_ref().filter(|_| self.in_text) {
+            let text_chars: Vec<char> = process_args.join(" ").chars().collect();
+            let start = text_range.start.min(text_chars.len());
+            let line_start = text_chars[..start].iter().rposition(|character| '\n' == *character).map_or(0, |idx| idx + 1);
+            let line_end = text_chars[start..].iter().position(|character| '\n' == *character).map_or(text_chars.len(), |idx| start + idx);
+            let end = text_range.end.clamp(start, line_end);
+            result.push_str(&format!("{}{}\n", RENDER_INDENT, text_chars[line_start..line_end].iter().collect::<String>()));
+            result.push_str(&format!("{}{}{}\n", RENDER_INDENT, " ".repeat(width_of(&text_chars[line_start..start])),
+                                paint(&"^".repeat(width_of(&text_chars[start..end]).max(1)), ANSI_ERROR_STYLE, colored)));
+        } else if let (Some(arg_index), Some(char_range)) = (self.arg_index, &self.char_range) {
+            if arg_index < process_args.len() {
+                let arg_chars: Vec<char> = process_args[arg_index].chars().collect();
+                let start = char_range.start.min(arg_chars.len());
+                let end = char_range.end.clamp(start, arg_chars.len());
+                let column: usize = process_args[..arg_index].iter()
+                                        .map(|arg| display_width(arg) + 1).sum::<usize>() + width_of(&arg_chars[..start]);
+                result.push_str(&format!("{}{}\n", RENDER_INDENT, process_args.join(" ")));
+                result.push_str(&format!("{}{}{}\n", RENDER_INDENT, " ".repeat(column),
+                                    paint(&"^".repeat(width_of(&arg_chars[start..end]).max(1)), ANSI_ERROR_STYLE, colored)));
+            }
+        }
+        result
+    }
 }
 
 impl Display for CommandLineParserError {
@@ -422,10 +732,47 @@ impl Debug for CommandLineParserError {
 /// PositionalArgumentsMode menas that all the options
 /// after - - are positional argument that user can pass
 /// to a process launched by your application
-#[derive(PartialEq)]
+/// PosixParsingMode means that parsing stops at the first
+/// argument that is not an option (or at - -), it and all the
+/// following arguments are positional arguments, as getopt does
+/// when POSIXLY_CORRECT is set (i.g. for time or nice like wrappers)
+#[derive(PartialEq, Clone, Copy)]
 pub enum ParsingMode {
     DefaultParsingMode,
     PositionalArgumentsMode,
+    PosixParsingMode,
+}
+
+/// Command line parser color mode
+/// AutoColorMode means that the output is colored if it is a terminal,
+/// unless the NO_COLOR environment variable is set, or if the
+/// CLICOLOR_FORCE environment variable is set (and not 0)
+/// AlwaysColorMode and NeverColorMode override the detection
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ColorMode {
+    AutoColorMode,
+    AlwaysColorMode,
+    NeverColorMode,
+}
+
+impl ColorMode {
+    /// Method that returns if an output has to be colored
+    /// * `is_terminal` - true if the output is a terminal
+    pub fn is_colored(&self, is_terminal: bool) -> bool {
+        match self {
+            ColorMode::AlwaysColorMode => true,
+            ColorMode::NeverColorMode => false,
+            ColorMode::AutoColorMode => {
+                if std::env::var_os(NO_COLOR_ENV_VAR).is_some_and(|value| !value.is_empty()) {
+                    false
+                } else if std::env::var_os(CLICOLOR_FORCE_ENV_VAR).is_some_and(|value| !value.is_empty() && value != "0") {
+                    true
+                } else {
+                    is_terminal
+                }
+            }
+        }
+    }
 }
 
 impl ParsingMode {
@@ -439,6 +786,9 @@ impl ParsingMode {
             },
             ParsingMode::PositionalArgumentsMode => {
                 "PositionalArgumentsMode"
+            },
+            ParsingMode::PosixParsingMode => {
+                "PosixParsingMode"
             }
         }
     }
@@ -455,9 +805,9 @@ impl Display for ParsingMode {
 /// Arguments could be of two types, single character argument (i.g -c)
 /// or long text argument (i.g. --config-file)
 /// Process argument could be of the following type:
-/// Boolean - classic is -h to show process help, 
-/// Integer - an integer value for example verbosity level --verbose 5, 
-/// Floating point - a floating point numer --ratio=123.25, 
+/// Boolean - classic is -h to show process help,
+/// Integer - an integer value for example verbosity level --verbose 5,
+/// Floating point - a floating point numer --ratio=123.25,
 /// String - a text argument, classic configuration file path --config-file app.properties.
 /// Integer, Floating point and String option has a mandatory angument
 /// while Boolean option does not require an argument.
@@ -465,27 +815,50 @@ impl Display for ParsingMode {
 /// or --config-file config/app.properties the same example is valid for single character options
 /// for example -c=onfig/app.properties or -c onfig/app.properties.
 /// Single character option can be pass grouped together (i.g -xvz).
-/// Be carful if a single character argument needs an argument you have to pass it or 
+/// Be carful if a single character argument needs an argument you have to pass it or
 /// adding = and the value (i.g. -xvzf=file_to_compress.tar.gz) or as next process argument
 /// (i.g -xvzf file_to_compress.tar.gz).
 /// No more than a single character option with a mandatory argument can be grouped.
+/// The parser holds only the options definition, it is never modified
+/// by a parse call, so the same parser can parse many command lines
+/// (even from several threads at once), each parse call returns its own ParseResult.
+#[derive(Clone)]
 pub struct CommandLineParser {
     program_name: String,
     options: Vec<CommandLineOption>,
-    errors_list: Vec<String>,
-    remaining_args: Vec<String>,
-    positional_args: Vec<String>,
-    parsing_mode: ParsingMode
+    errors_catalog: ErrorCatalog,
+    parsing_mode: ParsingMode,
+    show_aliases: bool,
+    prompt_missing: bool,
+    help_option: Option<u64>,
+    help_all_option: Option<u64>,
+    version_option: Option<u64>,
+    version: String,
+    auto_help_version: bool,
+    collect_errors: bool,
+    help_width: Option<usize>,
+    color_mode: ColorMode,
+    about: String,
+    usage: String,
+    examples: Vec<(String, String)>,
+    epilog: String,
+    sort_help: bool,
+    getopt_compatible: bool,
+    posixly_correct: bool,
+    commands: Vec<(String, CommandLineParser)>,
+    external_commands: bool,
+    docopt: docopt::DocoptDefinition
 }
 
 
 impl CommandLineParser {
     /// Associated funtion to create a CommandLineParser
-    /// * `errors_list` - Optional list of errors (None means default Elnglish language)
+    /// * `errors_list` - Optional list of errors (None means default Elnglish language),
+    ///   see with_catalog to use a messages catalog
     /// # Examples
     /// ```
     /// use rsclp::{CommandLineParser, CommandLineParserError, CMD_LINE_OPTION_ERROR_NUM};
-    /// 
+    ///
     /// fn main()  {
     ///     let it_error_list: [&str; CMD_LINE_OPTION_ERROR_NUM] = [
     ///        "l'opzione già esiste",
@@ -498,50 +871,60 @@ impl CommandLineParser {
     ///         "valore booleano non valido per questo tipo di opzione",
     ///         "valore intero non valido per questo tipo di opzione",
     ///         "valore a virgola mobile non valido per questo tipo di opzione",
-    ///         "manca l'argomento per questa opzione", 
+    ///         "manca l'argomento per questa opzione",
     ///         "opzione obbligatoria non valorizzata",
     ///         "identificativo dell'opzione non trovato",
     ///         "argomento dell'opzione fia assegnato"
     ///     ];
     ///     let mut clp = CommandLineParser::new(Some(it_error_list));
-    ///     
-    ///     
+    ///
+    ///
     /// }
     /// ```
-    /// 
+    ///
     pub fn new(errors_list: Option<[&str; CMD_LINE_OPTION_ERROR_NUM]>) -> Self {
-        let mut result = Self {
+        match errors_list {
+            Some(list) => Self::with_catalog(ErrorCatalog::from_list(list)),
+            None => Self::with_catalog(ErrorCatalog::english())
+        }
+    }
+
+    /// Associated funtion to create a CommandLineParser
+    /// using a messages catalog (see ErrorCatalog)
+    /// * `errors_catalog` - catalog of error and warning messages
+    pub fn with_catalog(errors_catalog: ErrorCatalog) -> Self {
+        Self {
             program_name: String::new(),
             options: vec![],
-            errors_list: vec![],
-            remaining_args: vec![],
-            positional_args: vec![],
-            parsing_mode: ParsingMode::DefaultParsingMode
-        };
-        match errors_list {
-            Some(list) => {
-                for element in list {
-                    result.errors_list.push(element.to_string());
-                }
-            },
-            None => {
-                result.errors_list.push(OPTION_ALREADY_EXISTS_ERROR.to_string());
-                result.errors_list.push(SHORT_OPTION_ALREADY_EXISTS_ERROR.to_string());
-                result.errors_list.push(LONG_OPTION_ALREADY_EXISTS_ERROR.to_string());
-                result.errors_list.push(OPTION_IS_NOT_SET_ERROR.to_string());
-                result.errors_list.push(OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR.to_string());
-                result.errors_list.push(OPTION_NOT_FOUND_ERROR.to_string());
-                result.errors_list.push(OPTION_TYPE_UNDEFINED_ERROR.to_string());
-                result.errors_list.push(BOOLEAN_OPTION_MISMATCH_VALUE_SET_ERROR.to_string());
-                result.errors_list.push(INTEGER_OPTION_MISMATCH_VALUE_SET_ERROR.to_string());
-                result.errors_list.push(FPOINT_OPTION_MISMATCH_VALUE_SET_ERROR.to_string());
-                result.errors_list.push(MISSING_OPTION_ARGUMENT_ERROR.to_string());
-                result.errors_list.push(MANDATORY_OPTION_HAS_NOT_SET_ERROR.to_string());
-                result.errors_list.push(OPTION_IDENTIFIER_NOT_FOUND_ERROR.to_string());
-                result.errors_list.push(OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR.to_string());
-            }
+            errors_catalog,
+            parsing_mode: ParsingMode::DefaultParsingMode,
+            show_aliases: false,
+            prompt_missing: false,
+            help_option: None,
+            help_all_option: None,
+            version_option: None,
+            version: String::new(),
+            auto_help_version: true,
+            collect_errors: false,
+            help_width: None,
+            color_mode: ColorMode::AutoColorMode,
+            about: String::new(),
+            usage: String::new(),
+            examples: vec![],
+            epilog: String::new(),
+            sort_help: false,
+            getopt_compatible: false,
+            posixly_correct: false,
+            commands: vec![],
+            external_commands: false,
+            docopt: docopt::DocoptDefinition::default()
         }
-        result
+    }
+
+    /// Method to set the catalog of error and warning messages
+    /// * `errors_catalog` - messages catalog
+    pub fn set_errors_catalog(&mut self, errors_catalog: ErrorCatalog) {
+        self.errors_catalog = errors_catalog;
     }
 
     /// Method to add a boolean command line option identified
@@ -887,263 +1270,821 @@ impl CommandLineParser {
 
 
     /// Method to add the classic help command line option
-    /// -h & --help
+    /// -h & --help, process and process_os methods show
+    /// the help text and exit when it is set (see set_auto_help_version)
     /// * `help_text` - command line option halt text 
     pub fn add_help_option(&mut self, help_text: &str) -> StdResult<u64, CommandLineParserError> {
-        self.add_boolean_option('h', 
+        let option_hash = self.add_boolean_option('h', 
                                 "help", 
-                                false, help_text)
+                                false, help_text)?;
+        self.help_option = Some(option_hash);
+        Ok(option_hash)
     }
 
     /// Method to add the classic version command line option
-    /// -v & --version
+    /// -v & --version, process and process_os methods show
+    /// the version (see set_version) and exit when it is set
     /// * `help_text` - command line option halt text 
     pub fn add_version_option(&mut self, help_text: &str) -> StdResult<u64, CommandLineParserError> {
-        self.add_boolean_option('v', 
+        let option_hash = self.add_boolean_option('v', 
                                 "version", 
-                                false, help_text)
+                                false, help_text)?;
+        self.version_option = Some(option_hash);
+        Ok(option_hash)
     }
 
-    /// Method to check if the parser found and set a command line option
-    /// during command line parsing phase
+    /// Method to add the help command line option
+    /// that shows hidden options too
+    /// --help-all, process and process_os methods show
+    /// the help all text and exit when it is set
+    /// * `help_text` - command line option halt text
+    pub fn add_help_all_option(&mut self, help_text: &str) -> StdResult<u64, CommandLineParserError> {
+        let option_hash = self.add_long_boolean_option("help-all",
+                                false, help_text)?;
+        self.help_all_option = Some(option_hash);
+        Ok(option_hash)
+    }
+
+    /// Method to set the version text shown when
+    /// the version option is set
+    /// * `version` - version text (i.g. myapp 1.0.2)
+    pub fn set_version(&mut self, version: &str) {
+        self.version = version.to_string();
+    }
+
+    /// Method to set the automatic handling of help and version options.
+    /// When it is enabled (the default) process and process_os methods show
+    /// the help text or the version on standard output and exit successfully
+    /// if the help or version option is set, even if other arguments are wrong;
+    /// parse still parses all the process arguments and returns their errors.
+    /// When it is disabled help and version options are plain boolean options
+    /// handled by the application, process and process_os still check
+    /// the mandatory options
+    /// * `auto_help_version` - false to handle help and version options manually
+    pub fn set_auto_help_version(&mut self, auto_help_version: bool) {
+        self.auto_help_version = auto_help_version;
+    }
+
+    /// Method to hide a command line option from the help text,
+    /// the option is still parsed and it is shown by the help all text
     /// * `option_hash` - command line option identifier returned by
-    /// an add_* method 
-    pub fn is_set(&self, option_hash: &u64) ->  bool {
-        let mut iter = self.options.iter();
-        while let Some(option) = iter.next() {
-            if *option_hash == option.calculate_hash() {
-                return option.is_set();
-            }
-        }
-        false
+    ///   an add_* method
+    /// * `hidden` - true to hide the option
+    pub fn set_option_hidden(&mut self, option_hash: &u64, hidden: bool) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.hidden = hidden;
+        Ok(())
     }
 
-    /// Generic method to get the value of a command line option
-    /// (the first encountered) during command line parsing phase
-    /// if command line option is not of the required type &ltT&gt or
-    /// is not set error is returned
+    /// Method to assign a command line option to a category,
+    /// the help text groups the options by category, each one under its heading.
+    /// Options without category are shown under the Options heading
     /// * `option_hash` - command line option identifier returned by
-    /// an add_* method 
-    pub fn get_value<T: FromStr + 'static>(&self, option_hash: &u64) -> StdResult<T, CommandLineParserError> {
-        let mut iter = self.options.iter();
-        while let Some(option) = iter.next() {
-            if *option_hash == option.calculate_hash() {
-                let option_value = option.get_value();
-                if let Some(value) = option_value {
-                    if let Ok(parsed_value) = value.parse::<T>() {
-                        let type_id = parsed_value.type_id();
-                        let parsed_value_type = CommandLineOptionType::from(type_id);
-                        if parsed_value_type == option.typ {
-                            return Ok(parsed_value);
-                        }
-                    }
-                    return Err(CommandLineParserError {
-                        flags: option.get_flags(),
-                        typ: option.get_type_name(),
-                        error: self.errors_list[OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX].clone()
-                    });
-                }
-                return Err(CommandLineParserError {
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: self.errors_list[OPTION_IS_NOT_SET_ERROR_IDX].clone()
-                });
-            }
-        }
+    ///   an add_* method
+    /// * `category` - category heading (i.g. "Network"), the heading
+    ///   is localized by the catalog message heading_&ltcategory&gt (see ErrorCatalog::get_heading),
+    ///   an empty text removes the option from its category
+    pub fn set_option_category(&mut self, option_hash: &u64, category: &str) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.category = category.to_string();
+        Ok(())
+    }
 
-        Err(CommandLineParserError {
-            flags: String::new(),
-            typ: COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
-            error: self.errors_list[OPTION_IDENTIFIER_NOT_FOUND_ERROR_IDX].clone()
-        })
-    } 
+    /// Method to set the long help text of a command line option,
+    /// shown by the long help text (i.g. --help) in place of the
+    /// one line help text shown by the help text (i.g. -h)
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `long_help` - long help text, new lines start new paragraphs
+    pub fn set_option_long_help(&mut self, option_hash: &u64, long_help: &str) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.long_help = long_help.to_string();
+        Ok(())
+    }
 
-    /// Generic method to get all values of a command line option
-    /// during command line parsing phase
-    /// i.g if the option is -f/--input-file &ltfile name&gt input file to be merged 
-    /// and the command line is -f file1.txt -o output.txt --input-file=file2.txt
-    /// get_value returns a vector containing file1.txt & file2.txt 
-    /// if command line option is not of the required type &ltT&gt or
-    /// is not set error is returned
+    /// Method to make the argument of a not boolean command line option optional,
+    /// the option can be set without argument (i.g. --color or -c) and
+    /// in that case its value is an empty text; an optional argument has to be
+    /// attached to the flag (i.g. --color=auto, -c=auto or -cauto for getopt parsers)
     /// * `option_hash` - command line option identifier returned by
-    /// an add_* method 
-    pub fn get_values<T: FromStr>(&self, option_hash: &u64) -> Option<Vec<T>> {
-        let mut values: Vec<T> = vec![];
-        for option in &self.options {
-            if *option_hash == option.calculate_hash() {
-                let option_values = option.get_values();
-                for option_value in option_values {
-                    if let Ok(parsed_value) = option_value.parse::<T>() {
-                        values.push(parsed_value);
-                    } else {
-                        eprintln!("{} {} {}", option.get_type_name(), option.get_flags(), self.errors_list[OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX]); 
-                        return None;
-                    }
+    ///   an add_* method
+    /// * `optional_argument` - true to make the argument optional
+    pub fn set_option_optional_argument(&mut self, option_hash: &u64, optional_argument: bool) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.optional_argument = optional_argument;
+        Ok(())
+    }
+
+    /// Method to set the help text order, by default categories and options
+    /// are shown in the adding order, when sorted categories are sorted by heading
+    /// and options by flags
+    /// * `sort_help` - true to sort categories and options
+    pub fn set_sort_help(&mut self, sort_help: bool) {
+        self.sort_help = sort_help;
+    }
+
+    /// Method to mark a command line option as deprecated,
+    /// the option is still parsed but each time it is found
+    /// a warning is recorded in the ParseResult (see ParseResult::get_warnings)
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `replacement` - replacement hint shown in the warning (i.g. --out-dir),
+    ///   empty string means no replacement
+    pub fn set_option_deprecated(&mut self, option_hash: &u64, replacement: &str) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.deprecated = Some(replacement.to_string());
+        Ok(())
+    }
+
+    /// Method to add a single character alias to an existing option
+    /// (i.g -V alongside -v/--version)
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `alias` - single character alias flag
+    pub fn add_short_alias(&mut self, option_hash: &u64, alias: char) -> StdResult<(), CommandLineParserError> {
+        let mut alias_option = CommandLineOption::new_short_only(alias, false, "", "");
+        alias_option.typ = self.get_option_by_hash_mut(option_hash)?.typ.clone();
+        self.check_option_already_exists(&alias_option)?;
+        self.get_option_by_hash_mut(option_hash)?.short_aliases.push(alias);
+        Ok(())
+    }
+
+    /// Method to add a long text alias to an existing option
+    /// (i.g --colour for --color)
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `alias` - long text alias flag
+    pub fn add_long_alias(&mut self, option_hash: &u64, alias: &str) -> StdResult<(), CommandLineParserError> {
+        let mut alias_option = CommandLineOption::new_long_only(alias, false, "", "");
+        alias_option.typ = self.get_option_by_hash_mut(option_hash)?.typ.clone();
+        self.check_option_already_exists(&alias_option)?;
+        self.get_option_by_hash_mut(option_hash)?.long_aliases.push(alias.to_string());
+        Ok(())
+    }
+
+    /// Method to mark an option as secret (i.g. a password),
+    /// when its value is asked on the terminal it is read without echo
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `secret` - true to read the value without echo
+    pub fn set_option_secret(&mut self, option_hash: &u64, secret: bool) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.secret = secret;
+        Ok(())
+    }
+
+    /// Method to set if process and process_os methods ask on the terminal
+    /// the missing mandatory options values (see ParseResult::prompt_missing)
+    /// instead of failing. The terminal echo is disabled while secret values
+    /// are read and it is not restored if the process is killed by a signal
+    /// (i.g. Ctrl-C), the crate does not install signal handlers
+    /// * `prompt_missing` - true to ask missing values, by default they are not asked
+    pub fn set_prompt_missing(&mut self, prompt_missing: bool) {
+        self.prompt_missing = prompt_missing;
+    }
+
+    /// Method to set the width of the help text in terminal columns,
+    /// long descriptions are wrapped to this width
+    /// * `help_width` - help text width, None means the COLUMNS
+    ///   environment variable value or 80 if it is not set
+    pub fn set_help_width(&mut self, help_width: Option<usize>) {
+        self.help_width = help_width;
+    }
+
+    /// Method that returns the width of the help text
+    /// in terminal columns (see set_help_width)
+    pub fn get_help_width(&self) -> usize {
+        self.help_width.unwrap_or_else(|| {
+            std::env::var("COLUMNS").ok()
+                .and_then(|columns| columns.trim().parse::<usize>().ok())
+                .filter(|columns| 0 != *columns)
+                .unwrap_or(DEFAULT_HELP_WIDTH)
+        })
+    }
+
+    /// Method to set if help text and errors are colored
+    /// using ANSI escape sequences
+    /// * `color_mode` - color mode, by default ColorMode::AutoColorMode
+    pub fn set_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+    }
+
+    /// Method to set if option aliases are listed in the help text
+    /// * `show_aliases` - true to list aliases, by default they are not listed
+    pub fn set_show_aliases(&mut self, show_aliases: bool) {
+        self.show_aliases = show_aliases;
+    }
+
+    /// Method to retrive the global command line help text,
+    /// hidden options are not shown
+    pub fn get_help_text(&self) -> String {
+        self.get_help_text_for(&self.program_name, false, false, false)
+    }
+
+    /// Method to retrive the global command line long help text,
+    /// each option is shown with its long help text (see set_option_long_help)
+    /// on the lines following its flags, hidden options are not shown
+    pub fn get_long_help_text(&self) -> String {
+        self.get_help_text_for(&self.program_name, false, true, false)
+    }
+
+    /// Method to retrive the global command line help text
+    /// hidden options included
+    pub fn get_help_all_text(&self) -> String {
+        self.get_help_text_for(&self.program_name, true, false, false)
+    }
+
+    // Method that returns if at least a visible option
+    // has a long help text (see set_option_long_help)
+    fn has_long_help(&self) -> bool {
+        self.options.iter().any(|option| !option.hidden && !option.long_help.is_empty())
+    }
+
+    // Method to retrive the global command line help text
+    // using the passed program name in the help header
+    // * `program_name` - program name shown in the help header
+    // * `include_hidden` - true to show hidden options too
+    // * `long_help` - true to show the options long help text
+    // * `colored` - true to color the help text with ANSI escape sequences
+    fn get_help_text_for(&self, program_name: &str, include_hidden: bool, long_help: bool, colored: bool) -> String {
+        let mut result = String::new();
+        let help_width = self.get_help_width();
+        let push_paragraphs = |result: &mut String, text: &str, indent: &str, width: usize| {
+            for paragraph in text.lines() {
+                for line in wrap_text(paragraph, width) {
+                    result.push_str(&format!("{}{}\n", indent, line));
                 }
             }
+        };
+        if !self.about.is_empty() {
+            push_paragraphs(&mut result, &self.about, "", help_width);
+            result.push('\n');
         }
-        if values.len() > 0 {
-            Some(values)
-        } else {
-            None
+        let mut usage = self.get_usage_text_for(program_name);
+        if !usage.is_empty() {
+            if self.usage.is_empty() {
+                usage.push(':');
+            }
+            result.push_str(&format!("{}\n", paint(&usage, ANSI_HEADING_STYLE, colored)));
         }
+        let max_flags_len = self.max_flags_len(include_hidden);
+        let max_arg_text_len = self.max_arg_text_len(include_hidden);
+        let description_width = self.get_help_width()
+                                    .saturating_sub(HELP_TAB_WIDTH + max_flags_len + max_arg_text_len + 4)
+                                    .max(MIN_HELP_DESCRIPTION_WIDTH);
+        let mut options: Vec<&CommandLineOption> = self.options.iter()
+                                                        .filter(|option| include_hidden || !option.hidden).collect();
+        if self.sort_help {
+            options.sort_by_key(|option| option.get_help_flags(false).trim_start_matches('-').to_lowercase());
+        }
+        let mut categories: Vec<(String, Vec<&CommandLineOption>)> = vec![];
+        for option in options {
+            let heading = if option.category.is_empty() { OPTIONS_HEADING } else { option.category.as_str() };
+            let heading = self.errors_catalog.get_heading(heading);
+            match categories.iter_mut().find(|(category, _)| *category == heading) {
+                Some((_, category_options)) => category_options.push(option),
+                None => categories.push((heading, vec![option]))
+            }
+        }
+        if self.sort_help {
+            categories.sort_by(|(first, _), (second, _)| first.cmp(second));
+        }
+        let long_help_width = help_width.saturating_sub(HELP_TAB_WIDTH + RENDER_INDENT.len())
+                                    .max(MIN_HELP_DESCRIPTION_WIDTH);
+        let show_headings = self.options.iter().any(|option| !option.category.is_empty());
+        for (heading, category_options) in categories {
+            if show_headings {
+                if !result.is_empty() {
+                    result.push('\n');
+                }
+                result.push_str(&format!("{}\n", paint(&format!("{}:", heading), ANSI_HEADING_STYLE, colored)));
+            }
+            for (option_idx, option) in category_options.iter().enumerate() {
+                if !long_help {
+                    result.push_str(&format!("\t{}", option.help_text(max_flags_len, max_arg_text_len,
+                                                                        self.show_aliases, description_width, colored)));
+                    continue;
+                }
+                if 0 != option_idx {
+                    result.push('\n');
+                }
+                result.push_str(&format!("\t{}", option.long_help_text(self.show_aliases, long_help_width, colored)));
+            }
+        }
+        if !self.examples.is_empty() {
+            let heading = self.errors_catalog.get_heading(EXAMPLES_HEADING);
+            result.push_str(&format!("\n{}\n", paint(&format!("{}:", heading), ANSI_HEADING_STYLE, colored)));
+            let example_indent = format!("\t{}", RENDER_INDENT);
+            for (command, description) in &self.examples {
+                result.push_str(&format!("\t{}\n", paint(command, ANSI_FLAGS_STYLE, colored)));
+                push_paragraphs(&mut result, description, &example_indent, long_help_width);
+            }
+        }
+        if !self.epilog.is_empty() {
+            result.push('\n');
+            push_paragraphs(&mut result, &self.epilog, "", help_width);
+        }
+
+        result
     }
 
-    /// Method to retrive the global command line help text
-    pub fn get_help_text(&self) -> String {
-        let mut result = String::new();
-        if !self.program_name.is_empty() {
-            result.push_str(&format!("{} [OPTIONS]:\n", self.program_name));
+    /// Method to set the program description shown
+    /// at the beginning of the help text, the text is wrapped
+    /// to the help width, new lines start new paragraphs
+    /// * `about` - program description
+    pub fn set_about(&mut self, about: &str) {
+        self.about = about.to_string();
+    }
+
+    /// Method to set the usage line shown as it is before the options in the help text,
+    /// if it is not set the usage line is built from the program name,
+    /// the mandatory options and the positional arguments (see get_usage_text)
+    /// * `usage` - usage line (i.g. "app [OPTIONS] -c <file path> <input>...")
+    pub fn set_usage(&mut self, usage: &str) {
+        self.usage = usage.to_string();
+    }
+
+    /// Method to add an example shown in the help text
+    /// after the options, examples are shown in the adding order
+    /// * `command` - example command line
+    /// * `description` - example description
+    pub fn add_example(&mut self, command: &str, description: &str) {
+        self.examples.push((command.to_string(), description.to_string()));
+    }
+
+    /// Method to set the closing text shown
+    /// at the end of the help text, the text is wrapped
+    /// to the help width, new lines start new paragraphs
+    /// * `epilog` - closing text
+    pub fn set_epilog(&mut self, epilog: &str) {
+        self.epilog = epilog.to_string();
+    }
+
+    /// Method that returns the usage line of the help text,
+    /// the one set by set_usage or one built from the program name followed by
+    /// [OPTIONS], the mandatory options not hidden and [-- &ltarguments&gt...]
+    /// if the parsing mode is PositionalArgumentsMode or PosixParsingMode
+    /// (i.g. app [OPTIONS] -c &ltfile path&gt).
+    /// An empty text is returned if neither the usage nor the program name are set
+    pub fn get_usage_text(&self) -> String {
+        self.get_usage_text_for(&self.program_name)
+    }
+
+    /// Method to export the command line definition as JSON text,
+    /// hidden options are exported too. The schema is stable,
+    /// incompatible changes increase schema_version (see JSON_SCHEMA_VERSION).
+    /// Top level object members:
+    /// * `schema_version` - number, the JSON_SCHEMA_VERSION value
+    /// * `program_name` - string, program name set by set_program_name
+    /// * `version` - string, version set by set_version
+    /// * `about` - string, program description set by set_about
+    /// * `usage` - string, usage line (see get_usage_text)
+    /// * `examples` - array of objects with `command` and `description` strings
+    /// * `epilog` - string, closing text set by set_epilog
+    /// * `parsing_mode` - string, DefaultParsingMode, PositionalArgumentsMode or PosixParsingMode
+    /// * `options` - array of option objects in the adding order
+    ///
+    /// Option object members:
+    /// * `id` - string, option flags (i.g. -v/--verbose or --dry-run), stable across
+    ///   program runs unlike the identifier returned by the add_* methods
+    /// * `short` - string with the single character flag or null
+    /// * `long` - string with the long text flag or null
+    /// * `short_aliases`, `long_aliases` - arrays of strings
+    /// * `type` - string, BooleanCommandLineOptionType, IntegerCommandLineOptionType,
+    ///   FPointCommandLineOptionType or StringCommandLineOptionType; help, help_all
+    ///   or version for the options added by add_help_option, add_help_all_option
+    ///   and add_version_option
+    /// * `mandatory`, `hidden`, `secret` - booleans
+    /// * `arg_text` - string, empty for boolean options
+    /// * `optional_argument` - boolean, true if the option argument can be omitted
+    ///   (see set_option_optional_argument)
+    /// * `scope` - string, GlobalScope, NextSectionScope or PreviousSectionScope
+    ///   (see set_option_scope)
+    /// * `section_marker` - boolean, true if the option starts a section
+    ///   (see set_option_section_marker)
+    /// * `help`, `long_help`, `category` - strings, empty if not set
+    /// * `deprecated` - null or an object with the `replacement` string (empty if none)
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+    ///     let json = clp.to_json();
+    ///     assert!(json.contains("\"long\": \"config\""));
+    ///     assert!(json.contains("\"type\": \"StringCommandLineOptionType\""));
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let options = self.options.iter().map(|option| {
+            let typ = [(self.help_option, spec::HELP_SPEC_TYPE), (self.help_all_option, spec::HELP_ALL_SPEC_TYPE),
+                        (self.version_option, spec::VERSION_SPEC_TYPE)].iter()
+                        .find(|(special_option, _)| Some(option.calculate_hash()) == *special_option)
+                        .map_or_else(|| option.get_type_name(), |(_, typ)| typ.to_string());
+            let short = (EMPTY_SHORT_FORM != option.short_form_option).then(|| option.short_form_option.to_string());
+            let long = (!option.long_form_option.is_empty()).then_some(option.long_form_option.as_str());
+            JsonValue::object(vec![
+                ("id", JsonValue::String(option.get_flags())),
+                ("short", JsonValue::optional_string(short.as_deref())),
+                ("long", JsonValue::optional_string(long)),
+                ("short_aliases", JsonValue::Array(option.short_aliases.iter()
+                                                    .map(|alias| JsonValue::String(alias.to_string())).collect())),
+                ("long_aliases", JsonValue::Array(option.long_aliases.iter()
+                                                    .map(|alias| JsonValue::String(alias.clone())).collect())),
+                ("type", JsonValue::String(typ)),
+                ("mandatory", JsonValue::Bool(option.mandatory)),
+                ("hidden", JsonValue::Bool(option.hidden)),
+                ("secret", JsonValue::Bool(option.secret)),
+                ("arg_text", JsonValue::String(option.arg_text.clone())),
+                ("optional_argument", JsonValue::Bool(option.optional_argument)),
+                ("scope", JsonValue::String(option.scope.to_string())),
+                ("section_marker", JsonValue::Bool(option.section_marker)),
+                ("help", JsonValue::String(option.help_text.clone())),
+                ("long_help", JsonValue::String(option.long_help.clone())),
+                ("category", JsonValue::String(option.category.clone())),
+                ("deprecated", option.deprecated.as_ref().map_or(JsonValue::Null, |replacement| {
+                    JsonValue::object(vec![("replacement", JsonValue::String(replacement.clone()))])
+                }))
+            ])
+        }).collect();
+        let examples = self.examples.iter().map(|(command, description)| {
+            JsonValue::object(vec![
+                ("command", JsonValue::String(command.clone())),
+                ("description", JsonValue::String(description.clone()))
+            ])
+        }).collect();
+        JsonValue::object(vec![
+            ("schema_version", JsonValue::Number(JSON_SCHEMA_VERSION)),
+            ("program_name", JsonValue::String(self.program_name.clone())),
+            ("version", JsonValue::String(self.version.clone())),
+            ("about", JsonValue::String(self.about.clone())),
+            ("usage", JsonValue::String(self.get_usage_text())),
+            ("examples", JsonValue::Array(examples)),
+            ("epilog", JsonValue::String(self.epilog.clone())),
+            ("parsing_mode", JsonValue::String(self.parsing_mode.unwrap().to_string())),
+            ("options", JsonValue::Array(options))
+        ]).render(0) + "\n"
+    }
+
+    // Method that returns the usage line of the help text
+    // using the passed program name (see get_usage_text)
+    // * `program_name` - program name shown in the usage line
+    fn get_usage_text_for(&self, program_name: &str) -> String {
+        if !self.usage.is_empty() {
+            return self.usage.clone();
         }
-        for option in &self.options {
-            result.push_str(&format!("\t{}", option.help_text(self.get_max_flags_len(), 
-                                                                    self.get_max_arg_text_len())));
+        if program_name.is_empty() {
+            return String::new();
+        }
+        let mut result = format!("{} {}", program_name, USAGE_OPTIONS_TEXT);
+        for option in self.options.iter().filter(|option| option.mandatory && !option.hidden) {
+            if EMPTY_SHORT_FORM != option.short_form_option {
+                result.push_str(&format!(" -{}", option.short_form_option));
+            } else {
+                result.push_str(&format!(" --{}", option.long_form_option));
+            }
+            if !option.arg_text.is_empty() {
+                result.push_str(&format!(" <{}>", option.arg_text));
+            }
+        }
+        if ParsingMode::DefaultParsingMode != self.parsing_mode {
+            result.push_str(&format!(" {}", USAGE_POSITIONAL_ARGS_TEXT));
         }
-
         result
     }
 
-    /// Method to show on standard output 
-    /// the global command line help text
+    /// Method to show on standard output
+    /// the global command line help text,
+    /// colored according to the color mode (see set_color_mode)
     pub fn show_help(&self) {
-        println!("{}", self.get_help_text());
+        let colored = self.color_mode.is_colored(std::io::stdout().is_terminal());
+        println!("{}", self.get_help_text_for(&self.program_name, false, false, colored));
     }
 
-    /// Method to show on object that implements
-    /// the std::io::Write trait 
-    /// the global command line help text
-    /// * `writer` - Write trait to show help text
-    /// Writer::write_all is used
-    pub fn show_help_on(&self, writer: &mut dyn Write) -> IOResult<()>{
-        writer.write_all(self.get_help_text().as_bytes())
+    /// Method to show on standard output
+    /// the global command line long help text (see get_long_help_text),
+    /// colored according to the color mode (see set_color_mode)
+    pub fn show_long_help(&self) {
+        let colored = self.color_mode.is_colored(std::io::stdout().is_terminal());
+        println!("{}", self.get_help_text_for(&self.program_name, false, true, colored));
     }
 
-    /// Method to retrieve all arguments not related to an option
-    pub fn get_remaining_args(&self) -> &Vec<String> {
-        &self.remaining_args
+    /// Method to show on standard output
+    /// the global command line help text hidden options included,
+    /// colored according to the color mode (see set_color_mode)
+    pub fn show_help_all(&self) {
+        let colored = self.color_mode.is_colored(std::io::stdout().is_terminal());
+        println!("{}", self.get_help_text_for(&self.program_name, true, false, colored));
     }
 
-    /// Method to retrieve all positionale arguments 
-    /// if ParsingMode is DefaultParsingMode the returned
-    /// vector is empty
-    pub fn get_positional_args(&self) ->&Vec<String> {
-        &self.positional_args
+    /// Method to show on object that implements
+    /// the std::io::Write trait
+    /// the global command line help text.
+    /// The writer is not known to be a terminal, so with ColorMode::AutoColorMode
+    /// the text is colored only if CLICOLOR_FORCE is set (see set_color_mode)
+    /// * `writer` - Write trait to show help text,
+    ///   Writer::write_all is used
+    pub fn show_help_on(&self, writer: &mut dyn Write) -> IOResult<()>{
+        let colored = self.color_mode.is_colored(false);
+        writer.write_all(self.get_help_text_for(&self.program_name, false, false, colored).as_bytes())
+    }
+
+    /// Method to set the program name shown in the help text header,
+    /// the name found by a parse call is available through
+    /// ParseResult::get_program_name
+    /// * `program_name` - program name
+    pub fn set_program_name(&mut self, program_name: &str) {
+        self.program_name = program_name.to_string();
     }
 
     /// Method to set parsing mode
-    /// * `parsing_mode` - command line parser parsing mode 
+    /// * `parsing_mode` - command line parser parsing mode
     pub fn set_parsing_mode(&mut self, parsing_mode: ParsingMode) {
         self.parsing_mode = parsing_mode;
     }
 
-    /// Method to parse arguments of a process, 
+    /// Method to set if the POSIXLY_CORRECT environment variable is honored,
+    /// when it is honored and the variable is set the parser uses
+    /// ParsingMode::PosixParsingMode whatever parsing mode has been set
+    /// * `posixly_correct` - true to honor POSIXLY_CORRECT, by default it is not honored
+    pub fn set_posixly_correct(&mut self, posixly_correct: bool) {
+        self.posixly_correct = posixly_correct;
+    }
+
+    /// Method that returns the parsing mode used by the parse methods,
+    /// the one set by set_parsing_mode or PosixParsingMode
+    /// if POSIXLY_CORRECT is honored and set (see set_posixly_correct)
+    pub fn get_parsing_mode(&self) -> ParsingMode {
+        self.get_parsing_mode_for(std::env::var_os(POSIXLY_CORRECT_ENV_VAR).is_some())
+    }
+
+    // Method that returns the parsing mode used by the parse methods
+    // (see get_parsing_mode)
+    // * `posixly_correct_set` - true if POSIXLY_CORRECT is set
+    fn get_parsing_mode_for(&self, posixly_correct_set: bool) -> ParsingMode {
+        if self.posixly_correct && posixly_correct_set {
+            ParsingMode::PosixParsingMode
+        } else {
+            self.parsing_mode
+        }
+    }
+
+    /// Method to parse arguments of a process,
     /// * `args_os` - an iterator over the arguments of a process, yielding an OsString value for each argument.
-    pub fn parse_args_os(&mut self, args_os: ArgsOs) -> StdResult<(), CommandLineParserError> {
-        let process_args: Vec<String> = args_os.map(|elem| { 
-            elem.into_string().unwrap_or(String::new()) }).collect();
-            self.parse(&process_args)
+    pub fn parse_args_os(&self, args_os: ArgsOs) -> StdResult<ParseResult, CommandLineParserError> {
+        let process_args: Vec<String> = args_os.map(|elem| {
+            elem.into_string().unwrap_or_default() }).collect();
+        self.parse(&process_args)
     }
 
-    /// Method to parse arguments of a process, 
+    /// Method to parse arguments of a process,
     /// * `args` - an iterator over the arguments of a process, yielding a String value for each argument.
-    pub fn parse_args(&mut self, args: Args) -> StdResult<(), CommandLineParserError> {
+    pub fn parse_args(&self, args: Args) -> StdResult<ParseResult, CommandLineParserError> {
         let process_args: Vec<String> = args.collect();
         self.parse(&process_args)
     }
 
     /// Method to process arguments of a process.
-    /// As opposed to parse_args method, process controls the returned 
+    /// As opposed to parse_args method, process controls the returned
     /// value of the parse_args method and in case of error it shows
-    /// the error and the help text on standar error 
-    pub fn process(&mut self) {
-        if let Err(parse_error) = self.parse_args(std::env::args()) {
-            eprintln!("{}", parse_error);            
-            let _ = self.show_help_on(&mut std::io::stderr().lock());
-            std::process::exit(-1);
-        }
+    /// the error and the help text on standar error
+    pub fn process(&self) -> ParseResult {
+        let process_args: Vec<String> = std::env::args().collect();
+        self.process_args(&process_args)
     }
 
     /// Method to process arguments of a process.
     /// Performs the same functionality as the process method but using ArgOs
-    pub fn process_os(&mut self) {
-        if let Err(parse_error) = self.parse_args_os(std::env::args_os()) {
-            eprintln!("{}", parse_error);            
-            let _ = self.show_help_on(&mut std::io::stderr().lock());
-            std::process::exit(-1);
+    pub fn process_os(&self) -> ParseResult {
+        let process_args: Vec<String> = std::env::args_os().map(|elem| {
+            elem.into_string().unwrap_or_default() }).collect();
+        self.process_args(&process_args)
+    }
+
+    // Method that realizes process and process_os,
+    // in case of error it shows the error and the help text
+    // on standard error and exits
+    // * `process_args` - process arguments as a strings slice
+    fn process_args(&self, process_args: &[String]) -> ParseResult {
+        let (mut result, mut parse_errors) = self.parse_collecting(process_args, self.collect_errors);
+        let colored = self.color_mode.is_colored(std::io::stdout().is_terminal());
+        if self.auto_help_version && self.show_requested_info(&result, &mut std::io::stdout().lock(), colored) {
+            std::process::exit(0);
+        }
+        if parse_errors.is_empty() && self.prompt_missing {
+            if let Err(parse_error) = result.prompt_missing() {
+                parse_errors.push(parse_error);
+            }
+        }
+        if parse_errors.is_empty() {
+            if self.collect_errors {
+                if let Err(mandatory_errors) = result.check_all_mandatory_options() {
+                    parse_errors = mandatory_errors;
+                }
+            } else if let Err(parse_error) = result.check_mandatory_options() {
+                parse_errors.push(parse_error);
+            }
+        }
+        if parse_errors.is_empty() {
+            return result;
+        }
+        let colored = self.color_mode.is_colored(std::io::stderr().is_terminal());
+        for parse_error in parse_errors {
+            eprint!("{}", parse_error.render_with(process_args, colored));
+        }
+        let mut program_name = self.program_name.as_str();
+        if program_name.is_empty() {
+            program_name = process_args.first().map_or("", |arg| arg.as_str());
+        }
+        let _ = std::io::stderr().lock().write_all(self.get_help_text_for(program_name, false, false, colored).as_bytes());
+        std::process::exit(-1);
+    }
+
+    /// Method that realizes the process arguments parsing,
+    /// the first argument is the program name.
+    /// The parser is not modified, all the parsed values are
+    /// returned in a new ParseResult
+    /// * `process_args` - process arguments as a strings slice
+    pub fn parse(&self, process_args: &[String]) -> StdResult<ParseResult, CommandLineParserError> {
+        let (result, mut parse_errors) = self.parse_collecting(process_args, false);
+        if parse_errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(parse_errors.remove(0))
         }
     }
 
-    // Method that realizes the process arguments parsing
-    // * `process_args` - process arguments as a strings vector reference
-    fn parse(&mut self, process_args: &Vec<String>) -> StdResult<(), CommandLineParserError> {
-        let mut args = process_args.clone();
-        self.program_name = args[0].clone();
-        args.remove(0);
-        let mut dashdash: bool = false;
-        let mut idx: usize = 0;
+    /// Method that realizes the process arguments parsing
+    /// as the parse method but it does not stop at the first error,
+    /// all the errors (type mismatches, missing arguments, unknown options)
+    /// are returned in the same order of the process arguments,
+    /// followed by all the mandatory options that have not been set.
+    /// Mandatory options are not checked if help or version option is set
+    /// (see set_auto_help_version)
+    /// * `process_args` - process arguments as a strings slice
+    pub fn parse_all(&self, process_args: &[String]) -> StdResult<ParseResult, Vec<CommandLineParserError>> {
+        let (result, mut parse_errors) = self.parse_collecting(process_args, true);
+        if !(self.auto_help_version && self.is_info_requested(&result)) {
+            if let Err(mandatory_errors) = result.check_all_mandatory_options() {
+                parse_errors.extend(mandatory_errors);
+            }
+        }
+        if parse_errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(parse_errors)
+        }
+    }
+
+    /// Method to set if process and process_os methods
+    /// show all the errors found instead of the first one (see parse_all)
+    /// * `collect_errors` - true to show all the errors
+    pub fn set_collect_errors(&mut self, collect_errors: bool) {
+        self.collect_errors = collect_errors;
+    }
+
+    // Method that realizes parse and parse_all methods,
+    // it returns the parse result and the errors found
+    // * `process_args` - process arguments as a strings slice
+    // * `collect_errors` - false to stop at the first error
+    fn parse_collecting(&self, process_args: &[String], collect_errors: bool) -> (ParseResult, Vec<CommandLineParserError>) {
+        let mut parse_errors: Vec<CommandLineParserError> = vec![];
+        let mut result = ParseResult {
+            program_name: String::new(),
+            options: self.options.clone(),
+            errors_catalog: self.errors_catalog.clone(),
+            remaining_args: vec![],
+            remaining_indexes: vec![],
+            positional_args: vec![],
+            positional_indexes: vec![],
+            warnings: vec![],
+            long_help_requested: false,
+            occurrences: vec![],
+            external_command: None,
+            docopt_arguments: vec![]
+        };
+        let args: &[String] = match process_args.split_first() {
+            Some((program_name, args)) => {
+                result.program_name = program_name.clone();
+                args
+            },
+            None => &[]
+        };
+        let parsing_mode = self.get_parsing_mode();
+        let mut dashdash: bool = false;
+        let mut idx: usize = 0;
         let args_len: usize = args.len();
         while idx < args_len {
             let arg: &String = &args[idx];
             if !dashdash {
-                if arg.starts_with("-") {
-                    if arg.starts_with("--") {
-                        self.parse_long_form_option(&args, arg, &mut idx, &mut dashdash)?;
+                let argument_kind = self.argument_kind(arg, parsing_mode);
+                if ArgumentKind::EndOfOptions == argument_kind {
+                    dashdash = true;
+                } else if ArgumentKind::Operand != argument_kind {
+                    let parsed = if ArgumentKind::LongOption == argument_kind {
+                        self.parse_long_form_option(&mut result, args, arg, &mut idx)
+                    } else if self.getopt_compatible {
+                        self.parse_getopt_short_option(&mut result, args, arg, &mut idx)
                     } else {
-                        self.parse_short_form_option(&args, arg, &mut idx)?;
+                        self.parse_short_form_option(&mut result, args, arg, &mut idx, &mut parse_errors)
+                    };
+                    if let Err(parse_error) = parsed {
+                        parse_errors.push(parse_error);
+                    }
+                    if !collect_errors && !parse_errors.is_empty() {
+                        break;
                     }
+                } else if ParsingMode::PosixParsingMode == parsing_mode {
+                    dashdash = true;
+                    result.positional_args.push(arg.clone());
+                    result.positional_indexes.push(idx + 1);
                 } else {
-                    self.remaining_args.push(arg.clone());
+                    result.remaining_args.push(arg.clone());
+                    result.remaining_indexes.push(idx + 1);
                 }
             } else {
-                self.positional_args.push(arg.clone());
+                result.positional_args.push(arg.clone());
+                result.positional_indexes.push(idx + 1);
             }
 
-            idx = idx + 1;
+            idx += 1;
         }
 
-        Ok(())
-    }
-
-    // Method to check if mandatory options
-    // has at least a value set
-    pub fn check_mandatory_options(&self) -> StdResult<(), CommandLineParserError> {
-        let mut iter = self.options.iter();
-        while let Some(option) = iter.next() {
-            if option.mandatory &&  !option.is_set() {
-                return Err(CommandLineParserError {
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: self.errors_list[MANDATORY_OPTION_HAS_NOT_SET_ERROR_IDX].clone()
-                });
-            }
+        self.apply_docopt(&mut result);
+        (result, parse_errors)
+    }
+
+    // Method that returns if help, help all or version
+    // option has been set in the parse result
+    // * `result` - parse result to check
+    fn is_info_requested(&self, result: &ParseResult) -> bool {
+        [self.help_option, self.help_all_option, self.version_option].iter()
+            .flatten()
+            .any(|option_hash| Ok(true) == result.get_value::<bool>(option_hash))
+    }
+
+    // Method to show the help text, the help all text or the version
+    // if the related option has been set in the parse result,
+    // the long help text is shown if the help option long form has been used.
+    // It returns true if something has been shown
+    // * `result` - parse result to check
+    // * `writer` - Write trait to show the text
+    // * `colored` - true to color the help text with ANSI escape sequences
+    fn show_requested_info(&self, result: &ParseResult, writer: &mut dyn Write, colored: bool) -> bool {
+        let mut program_name = self.program_name.as_str();
+        if program_name.is_empty() {
+            program_name = result.get_program_name();
         }
-        Ok(())
+        let is_requested = |option: &Option<u64>| {
+            option.is_some_and(|option_hash| Ok(true) == result.get_value::<bool>(&option_hash))
+        };
+        let text = if is_requested(&self.help_all_option) {
+            self.get_help_text_for(program_name, true, false, colored)
+        } else if is_requested(&self.help_option) {
+            self.get_help_text_for(program_name, false, result.long_help_requested && self.has_long_help(), colored)
+        } else if is_requested(&self.version_option) {
+            format!("{}\n", self.version)
+        } else {
+            return false;
+        };
+        let _ = writer.write_all(text.as_bytes());
+        true
     }
 
-
     // Method to parse single character option
-    // has at least a value set
-    // * `args` - remaining process arguments as a strings vector reference
+    // has at least a value set, unknown options are added to the errors
+    // and the following options of the same process argument are still parsed
+    // * `result` - parse result where values are stored
+    // * `args` - remaining process arguments as a strings slice
     // * `arg` - current process argument to be parsed
     // * `idx` - mutable  process argument index
-    fn parse_short_form_option(&mut self, args: &Vec<String>, arg: &String, idx: &mut usize) -> StdResult<(), CommandLineParserError> {
+    // * `parse_errors` - errors found, where unknown options are added
+    fn parse_short_form_option(&self, result: &mut ParseResult, args: &[String], arg: &str, idx: &mut usize,
+                               parse_errors: &mut Vec<CommandLineParserError>) -> StdResult<(), CommandLineParserError> {
         let args_len = args.len();
+        let arg_index = *idx + 1;
         let mut opt_arg = String::new();
-        let pos = arg.find(OPTION_ASSIGN_TAG).unwrap_or_else(|| {usize::MAX});
+        let mut opt_arg_position = (arg_index, 0..0);
         let mut opt = &arg[1..];
-        if pos != usize::MAX {
+        if let Some(pos) = arg.find(OPTION_ASSIGN_TAG) {
             opt = &arg[1..pos];
             opt_arg.push_str(&arg[pos + 1..]);
+            opt_arg_position = (arg_index, arg[..pos].chars().count() + 1..arg.chars().count());
         }
 
         let options: Vec<char> = opt.chars().collect();
-  
-        let mut opt_arg_assigned = false;
-        let mut char_idx = 0usize;
-        while char_idx < options.len() {
 
-            let opt = format!("-{}", options[char_idx]);
-            if let Some(option) = self.get_option_mut(&opt) {
+        let mut opt_arg_assigned = false;
+        for (char_idx, short_form_option) in options.into_iter().enumerate() {
+            let option_range = char_idx + 1..char_idx + 2;
+
+            let opt = format!("-{}", short_form_option);
+            let mut warning = None;
+            let mut option_hash = None;
+            if let Some(option) = result.get_option_mut(&opt) {
+                warning = option.deprecation_warning(&self.errors_catalog);
+                option_hash = Some(option.calculate_hash());
                 if option.typ.unwrap() == COMMAND_LINE_OPTION_TYPE_BOOLEAN {
                     let mut value = "true";
                     if !opt_arg.is_empty() {
@@ -1151,203 +2092,249 @@ impl CommandLineParser {
                             value = &opt_arg;
                             opt_arg_assigned = true;
                         } else {
-                            return Err(CommandLineParserError {
-                                flags: option.get_flags(),
-                                typ: option.get_type_name(),
-                                error: format!("{}", self.errors_list[OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX])
-                            });    
+                            return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                                self.errors_catalog.error(OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX, &option.get_flags(), ""),
+                                arg_index, option_range));
                         }
                     }
-                    if let Err(error_idx) = option.add_value(value) {
-                        return Err(CommandLineParserError {
-                            flags: option.get_flags(),
-                            typ: option.get_type_name(),
-                            error: format!("{}", self.errors_list[error_idx])
-                        });
+                    if let Err(error_idx) = option.add_value(value, arg_index) {
+                        return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                            self.errors_catalog.error(error_idx, &option.get_flags(), value),
+                            opt_arg_position.0, opt_arg_position.1));
                     }
                 } else {
-                    if opt_arg.is_empty() {
-                        if *idx < args_len - 1 {
-                            *idx = *idx + 1;
-                            opt_arg = args[*idx].clone();
-                        }
+                    if opt_arg.is_empty() && *idx < args_len - 1 && !option.optional_argument {
+                        *idx += 1;
+                        opt_arg = args[*idx].clone();
+                        opt_arg_position = (*idx + 1, 0..opt_arg.chars().count());
                     }
-                    if opt_arg.is_empty() {
-                        return Err(CommandLineParserError {
-                            flags: option.get_flags(),
-                            typ: option.get_type_name(),
-                            error: format!("{}", self.errors_list[MISSING_OPTION_ARGUMENT_ERROR_IDX])
-                        });
+                    if opt_arg.is_empty() && !option.optional_argument {
+                        return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                            self.errors_catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, &option.get_flags(), ""),
+                            arg_index, option_range));
                     }
                     if !opt_arg_assigned {
-                        if let Err(error_idx) = option.add_value(&opt_arg) {
-                            return Err(CommandLineParserError {
-                                flags: option.get_flags(),
-                                typ: option.get_type_name(),
-                                error: format!("{}", self.errors_list[error_idx])
-                            });
+                        if let Err(error_idx) = option.add_value(&opt_arg, opt_arg_position.0) {
+                            return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                                self.errors_catalog.error(error_idx, &option.get_flags(), &opt_arg),
+                                opt_arg_position.0, opt_arg_position.1));
                         }
                         opt_arg_assigned = true;
                     } else {
-                        return Err(CommandLineParserError {
-                            flags: option.get_flags(),
-                            typ: option.get_type_name(),
-                            error: format!("{}", self.errors_list[OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX])
-                        });
+                        return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                            self.errors_catalog.error(OPTION_ARGUMENT_ALREADY_ASSIGNED_ERROR_IDX, &option.get_flags(), ""),
+                            arg_index, option_range));
                     }
                 }
-            }    
-            char_idx = char_idx + 1usize;
+            } else {
+                parse_errors.push(CommandLineParserError::new_at(opt.clone(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                    self.errors_catalog.error(OPTION_NOT_FOUND_ERROR_IDX, &opt, &opt),
+                    arg_index, option_range));
+            }
+            if let Some(option_hash) = option_hash {
+                result.occurrences.push((arg_index, option_hash));
+            }
+            if let Some(warning) = warning {
+                result.warnings.push(warning);
+            }
+        }
+        Ok(())
+    }
+
+    // Method to parse single character options with the getopt rules:
+    // an option with argument takes the rest of the process argument (i.g. -bvalue)
+    // or, if it is required and nothing follows, the next process argument,
+    // = is not an assignment tag and unknown options are errors
+    // * `result` - parse result where values are stored
+    // * `args` - remaining process arguments as a strings slice
+    // * `arg` - current process argument to be parsed
+    // * `idx` - mutable  process argument index
+    fn parse_getopt_short_option(&self, result: &mut ParseResult, args: &[String], arg: &str, idx: &mut usize) -> StdResult<(), CommandLineParserError> {
+        let arg_index = *idx + 1;
+        let options: Vec<char> = arg.chars().skip(1).collect();
+        for (char_idx, short_form_option) in options.iter().enumerate() {
+            let option_range = char_idx + 1..char_idx + 2;
+            let opt = format!("-{}", short_form_option);
+            let Some(option) = result.get_option_mut(&opt) else {
+                return Err(CommandLineParserError::new_at(opt.clone(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                    self.errors_catalog.error(OPTION_NOT_FOUND_ERROR_IDX, &opt, &opt),
+                    arg_index, option_range));
+            };
+            let warning = option.deprecation_warning(&self.errors_catalog);
+            if option.get_type_name() == COMMAND_LINE_OPTION_TYPE_BOOLEAN {
+                let _ = option.add_value("true", arg_index);
+                let option_hash = option.calculate_hash();
+                result.occurrences.push((arg_index, option_hash));
+                if let Some(warning) = warning {
+                    result.warnings.push(warning);
+                }
+                continue;
+            }
+            let mut opt_arg: String = options[char_idx + 1..].iter().collect();
+            let mut opt_arg_position = (arg_index, char_idx + 2..options.len() + 1);
+            if opt_arg.is_empty() && !option.optional_argument {
+                if *idx + 1 >= args.len() {
+                    return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                        self.errors_catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, &option.get_flags(), ""),
+                        arg_index, option_range));
+                }
+                *idx += 1;
+                opt_arg = args[*idx].clone();
+                opt_arg_position = (*idx + 1, 0..opt_arg.chars().count());
+            }
+            if let Err(error_idx) = option.add_value(&opt_arg, opt_arg_position.0) {
+                return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(error_idx, &option.get_flags(), &opt_arg),
+                    opt_arg_position.0, opt_arg_position.1));
+            }
+            let option_hash = option.calculate_hash();
+            result.occurrences.push((arg_index, option_hash));
+            if let Some(warning) = warning {
+                result.warnings.push(warning);
+            }
+            break;
         }
         Ok(())
     }
 
     // Method to parse long text option
     // has at least a value set
-    // * `args` - remaining process arguments as a strings vector reference
+    // * `result` - parse result where values are stored
+    // * `args` - remaining process arguments as a strings slice
     // * `arg` - current process argument to be parsed
     // * `idx` - mutable  process argument index
-    fn parse_long_form_option(&mut self, args: &Vec<String>, arg: &String, idx: &mut usize, dashdash: &mut bool) -> StdResult<(), CommandLineParserError> {
+    fn parse_long_form_option(&self, result: &mut ParseResult, args: &[String], arg: &str, idx: &mut usize) -> StdResult<(), CommandLineParserError> {
         let args_len = args.len();
-        if arg == "--" {
-            if self.parsing_mode == ParsingMode::PositionalArgumentsMode {
-                *dashdash = true;
-                return Ok(());
-            }
-        }
-        
         let mut opt_and_arg: Vec<String> = arg.splitn(2, OPTION_ASSIGN_TAG)
                                             .map(|x| x.to_string()).collect();
+        let arg_index = *idx + 1;
+        let opt_len = opt_and_arg[0].chars().count();
+        let mut opt_arg_position = (arg_index, opt_len + 1..arg.chars().count());
         let opt = opt_and_arg.get_mut(0).unwrap();
         if opt == "--" {
             opt.push_str("--");
         }
-        if let Some(option) = self.get_option_mut(opt) {
+        let warning;
+        let option_hash;
+        let long_help_requested;
+        if let Some(option) = result.get_option_mut(opt) {
+            warning = option.deprecation_warning(&self.errors_catalog);
             let mut opt_arg;
             if opt_and_arg.len() < 2 {
                 if option.get_type_name() == COMMAND_LINE_OPTION_TYPE_BOOLEAN {
                    opt_arg = "true";
+                } else if option.optional_argument {
+                   opt_arg = "";
                 } else {
-                    *idx = *idx + 1;
+                    *idx += 1;
                     if *idx < args_len {
-                       opt_arg = args[*idx].as_str();     
+                       opt_arg = args[*idx].as_str();
+                       opt_arg_position = (*idx + 1, 0..opt_arg.chars().count());
                     } else {
-                        return Err(CommandLineParserError{
-                            flags: option.get_flags(),
-                            typ: option.get_type_name(),
-                            error: format!("{}", self.errors_list[MISSING_OPTION_ARGUMENT_ERROR_IDX])
-                        });                    
+                        return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                            self.errors_catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, &option.get_flags(), ""),
+                            arg_index, 0..opt_len));
                     }
                 }
                 opt_and_arg.push(opt_arg.to_string());
             }
             opt_arg = opt_and_arg[1].as_str();
-            if opt_arg.is_empty() {
-                return Err(CommandLineParserError{
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: format!("{}", self.errors_list[MISSING_OPTION_ARGUMENT_ERROR_IDX])
-                });                    
+            if opt_arg.is_empty() && !option.optional_argument {
+                return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(MISSING_OPTION_ARGUMENT_ERROR_IDX, &option.get_flags(), ""),
+                    arg_index, 0..arg.chars().count()));
             }
-            if let Err(error_idx) = option.add_value(opt_arg) {
-                return Err(CommandLineParserError {
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: format!("{}", self.errors_list[error_idx])
-                });
+            if let Err(error_idx) = option.add_value(opt_arg, opt_arg_position.0) {
+                return Err(CommandLineParserError::new_at(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(error_idx, &option.get_flags(), opt_arg),
+                    opt_arg_position.0, opt_arg_position.1));
             }
+            option_hash = option.calculate_hash();
+            long_help_requested = self.help_option == Some(option_hash) && Ok(true) == opt_arg.parse::<bool>();
         } else {
-            return Err(CommandLineParserError{
-                flags: arg.clone(),
-                typ: COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
-                error: format!("{}", self.errors_list[OPTION_NOT_FOUND_ERROR_IDX])
-            });                    
+            return Err(CommandLineParserError::new_at(arg.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                self.errors_catalog.error(OPTION_NOT_FOUND_ERROR_IDX, arg, arg),
+                arg_index, 0..opt_len));
         }
-        Ok(())
-    }
-
-    // Method to get a mutable reference to an existing option
-    // It returns an option so in case of a not added option
-    // the None value is returned
-    // * `searched_option` - option flag text to be searched 
-    fn get_option_mut(&mut self, searched_option: &String) -> Option<&mut CommandLineOption> {
-        let is_long = searched_option.starts_with("--");
-        let mut iter = self.options.iter_mut();
-        while let Some(option) = iter.next() {
-            if is_long {
-                if !option.long_form_option.is_empty() {
-                    if option.long_form_option == searched_option[2..] {
-                        return Some(option)
-                    }
-                }
-            } else {
-                if EMPTY_SHORT_FORM != option.short_form_option {
-                    if option.short_form_option == searched_option.chars().nth(1).unwrap_or_else(|| {' '}) {
-                        return Some(option);
-                    }
-                }
-            }
+        if let Some(warning) = warning {
+            result.warnings.push(warning);
         }
-        None
+        if long_help_requested {
+            result.long_help_requested = true;
+        }
+        result.occurrences.push((arg_index, option_hash));
+        Ok(())
     }
 
-
-
     // Method to check if a command line option already exists
     // or there are other option with the same single character or long text
-    // flags. It returns an Ok(()) in case option not exists already 
+    // flags. It returns an Ok(()) in case option not exists already
     // a CommandLineParserErr otherwise
     // * `option_to_check` - reference to an option to be checkd
     fn check_option_already_exists(&self, option_to_check: &CommandLineOption) -> StdResult<(), CommandLineParserError> {
-        let mut iter  = self.options.iter();
-        while let Some(option) = iter.next() {
+        for option in &self.options {
 
             if option_to_check == option {
-                return Err(CommandLineParserError{
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: self.errors_list[OPTION_ALREADY_EXISTS_ERROR_IDX].to_string()
-                });
+                return Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(OPTION_ALREADY_EXISTS_ERROR_IDX, &option.get_flags(), "")));
             }
 
-            if option_to_check.short_form_option == option.short_form_option {
-                    return StdResult::Err(CommandLineParserError {
-                        flags: option.get_flags(),
-                        typ: option.get_type_name(),
-                        error: self.errors_list[SHORT_OPTION_ALREADY_EXISTS_ERROR_IDX].to_string()
-                    });
+            if option.has_short_form(option_to_check.short_form_option) ||
+                option_to_check.short_aliases.iter().any(|alias| option.has_short_form(*alias)) {
+                    return StdResult::Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                        self.errors_catalog.error(SHORT_OPTION_ALREADY_EXISTS_ERROR_IDX, &option.get_flags(), "")));
             }
-            if option_to_check.long_form_option == option.long_form_option {
-                return StdResult::Err(CommandLineParserError {
-                    flags: option.get_flags(),
-                    typ: option.get_type_name(),
-                    error: self.errors_list[LONG_OPTION_ALREADY_EXISTS_ERROR_IDX].to_string()
-                });
+            if option.has_long_form(&option_to_check.long_form_option) ||
+                option_to_check.long_aliases.iter().any(|alias| option.has_long_form(alias)) {
+                return StdResult::Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(LONG_OPTION_ALREADY_EXISTS_ERROR_IDX, &option.get_flags(), "")));
             }
         }
         Ok(())
     }
 
+    // Method to get a mutable reference to an existing option
+    // from its identifier, an error is returned if the
+    // option identifier is not found
+    // * `option_hash` - command line option identifier returned by
+    //   an add_* method
+    fn get_option_by_hash_mut(&mut self, option_hash: &u64) -> StdResult<&mut CommandLineOption, CommandLineParserError> {
+        match self.options.iter_mut().find(|option| *option_hash == option.calculate_hash()) {
+            Some(option) => Ok(option),
+            None => Err(CommandLineParserError::new(String::new(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                self.errors_catalog.error(OPTION_IDENTIFIER_NOT_FOUND_ERROR_IDX, "", "")))
+        }
+    }
+
     // Method that returns the maximum length
-    // of all options flags, used to allign the help text
+    // of all shown options flags, used to allign the help text
     pub fn get_max_flags_len(&self) -> usize {
+        self.max_flags_len(false)
+    }
+
+    // Method that returns the maximum length
+    // of options flags, used to allign the help text
+    // * `include_hidden` - true to consider hidden options too
+    fn max_flags_len(&self, include_hidden: bool) -> usize {
         let mut result = 0usize;
         for option in &self.options {
-            let flags = option.get_flags();
-            if result < flags.len() {
-                result = flags.len();
+            let flags_len = display_width(&option.get_help_flags(self.show_aliases));
+            if (include_hidden || !option.hidden) && result < flags_len {
+                result = flags_len;
             }
         }
         result
     }
 
     // Method that returns the maximum length
-    // of all argument texts, used to allign the  help text
-    fn get_max_arg_text_len(&self) -> usize {
+    // of argument texts, used to allign the  help text
+    // * `include_hidden` - true to consider hidden options too
+    fn max_arg_text_len(&self, include_hidden: bool) -> usize {
         let mut result = 0usize;
         for option in &self.options {
-            if result < option.arg_text.len() {
-                result = option.arg_text.len();
+            let arg_text_len = display_width(&option.arg_text);
+            if (include_hidden || !option.hidden) && result < arg_text_len {
+                result = arg_text_len;
             }
         }
         result
@@ -1358,6 +2345,377 @@ impl CommandLineParser {
 }
 
 
+/// Result of a command line parsing, returned by the
+/// CommandLineParser parse methods.
+/// It holds all the values found in the parsed command line,
+/// the remaining and the positional arguments
+/// # Examples
+/// ```
+/// use rsclp::CommandLineParser;
+///
+/// fn main() {
+///     let mut clp = CommandLineParser::new(None);
+///     let verbose_option = clp.add_integer_option('V', "verbose", false, "level", "log verbosity level").unwrap();
+///     let first = clp.parse(&["app".to_string(), "-V".to_string(), "1".to_string()]).unwrap();
+///     let second = clp.parse(&["app".to_string(), "--verbose=2".to_string()]).unwrap();
+///     assert_eq!(Ok(1), first.get_value::<i32>(&verbose_option));
+///     assert_eq!(Ok(2), second.get_value::<i32>(&verbose_option));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ParseResult {
+    program_name: String,
+    options: Vec<CommandLineOption>,
+    errors_catalog: ErrorCatalog,
+    remaining_args: Vec<String>,
+    remaining_indexes: Vec<usize>,
+    positional_args: Vec<String>,
+    positional_indexes: Vec<usize>,
+    warnings: Vec<String>,
+    long_help_requested: bool,
+    occurrences: Vec<(usize, u64)>,
+    external_command: Option<(String, Vec<String>)>,
+    docopt_arguments: Vec<(u64, String, Vec<String>)>
+}
+
+impl Debug for ParseResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
+        f.debug_struct("ParseResult")
+            .field("program_name", &self.program_name)
+            .field("values", &self.options.iter()
+                                .filter(|option| option.is_set())
+                                .map(|option| (option.get_flags(), option.get_values()))
+                                .collect::<Vec<_>>())
+            .field("remaining_args", &self.remaining_args)
+            .field("positional_args", &self.positional_args)
+            .field("warnings", &self.warnings)
+            .finish()
+    }
+}
+
+
+impl ParseResult {
+    /// Method to retrieve the program name,
+    /// the first parsed argument
+    pub fn get_program_name(&self) -> &str {
+        &self.program_name
+    }
+
+    /// Method to check if the parser found and set a command line option
+    /// during command line parsing phase
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn is_set(&self, option_hash: &u64) ->  bool {
+        for option in &self.options {
+            if *option_hash == option.calculate_hash() {
+                return option.is_set();
+            }
+        }
+        self.get_docopt_argument(option_hash).is_some_and(|(_, values)| !values.is_empty())
+    }
+
+    /// Generic method to get the value of a command line option
+    /// (the first encountered) during command line parsing phase
+    /// if command line option is not of the required type &ltT&gt or
+    /// is not set error is returned
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn get_value<T: FromStr + 'static>(&self, option_hash: &u64) -> StdResult<T, CommandLineParserError> {
+        for option in &self.options {
+            if *option_hash == option.calculate_hash() {
+                let option_value = option.get_value();
+                if let Some(value) = option_value {
+                    if let Ok(parsed_value) = value.parse::<T>() {
+                        let type_id = parsed_value.type_id();
+                        let parsed_value_type = CommandLineOptionType::from(type_id);
+                        if parsed_value_type == option.typ {
+                            return Ok(parsed_value);
+                        }
+                    }
+                    return Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                        self.errors_catalog.error(OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX, &option.get_flags(), "")));
+                }
+                return Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(OPTION_IS_NOT_SET_ERROR_IDX, &option.get_flags(), "")));
+            }
+        }
+        if let Some((name, values)) = self.get_docopt_argument(option_hash) {
+            return match values.first() {
+                Some(value) => value.parse::<T>().map_err(|_| CommandLineParserError::new(name.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                    self.errors_catalog.error(OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX, name, value))),
+                None => Err(CommandLineParserError::new(name.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                    self.errors_catalog.error(OPTION_IS_NOT_SET_ERROR_IDX, name, "")))
+            };
+        }
+
+        Err(CommandLineParserError::new(String::new(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+            self.errors_catalog.error(OPTION_IDENTIFIER_NOT_FOUND_ERROR_IDX, "", "")))
+    }
+
+    /// Generic method to get all values of a command line option
+    /// during command line parsing phase
+    /// i.g if the option is -f/--input-file &ltfile name&gt input file to be merged
+    /// and the command line is -f file1.txt -o output.txt --input-file=file2.txt
+    /// get_value returns a vector containing file1.txt & file2.txt
+    /// if command line option is not of the required type &ltT&gt or
+    /// is not set error is returned
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn get_values<T: FromStr>(&self, option_hash: &u64) -> Option<Vec<T>> {
+        let mut values: Vec<T> = vec![];
+        for option in &self.options {
+            if *option_hash == option.calculate_hash() {
+                let option_values = option.get_values();
+                for option_value in option_values {
+                    if let Ok(parsed_value) = option_value.parse::<T>() {
+                        values.push(parsed_value);
+                    } else {
+                        eprintln!("{} {} {}", option.get_type_name(), option.get_flags(), self.errors_catalog.error(OPTION_IS_NOT_OF_REQUIRED_TYPE_ERROR_IDX, &option.get_flags(), option_value));
+                        return None;
+                    }
+                }
+            }
+        }
+        if let Some((_, argument_values)) = self.get_docopt_argument(option_hash) {
+            for argument_value in argument_values {
+                values.push(argument_value.parse::<T>().ok()?);
+            }
+        }
+        if !values.is_empty() {
+            Some(values)
+        } else {
+            None
+        }
+    }
+
+    /// Method to retrieve all the values set to a command line option
+    /// with the index of the process argument each value comes from
+    /// (i.g. 2 for the value of -c app.properties if -c is the first argument),
+    /// values not read from the process arguments (i.g. prompted ones) have index 0.
+    /// None is returned as by get_values
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn get_values_with_index<T: FromStr>(&self, option_hash: &u64) -> Option<Vec<(usize, T)>> {
+        let option = self.options.iter().find(|option| *option_hash == option.calculate_hash())?;
+        let indexes = option.value_indexes.iter().copied();
+        let values: Vec<T> = self.get_values(option_hash)?;
+        Some(indexes.zip(values).collect())
+    }
+
+    /// Method to retrieve the options found by the parsing phase
+    /// in the process arguments order, each one with the index of the
+    /// process argument of its flag and its identifier; an option is listed
+    /// each time it is found (i.g. -x -v -x lists -x twice)
+    pub fn get_options_order(&self) -> &Vec<(usize, u64)> {
+        &self.occurrences
+    }
+
+    /// Method to retrieve all arguments not related to an option
+    pub fn get_remaining_args(&self) -> &Vec<String> {
+        &self.remaining_args
+    }
+
+    /// Method to retrieve all positionale arguments
+    /// if ParsingMode is DefaultParsingMode the returned
+    /// vector is empty
+    pub fn get_positional_args(&self) ->&Vec<String> {
+        &self.positional_args
+    }
+
+    /// Method to retrieve all arguments not related to an option
+    /// with the index of the process argument of each one
+    /// (i.g. 3 for file of -v -c file if -c is a boolean option)
+    pub fn get_remaining_args_with_index(&self) -> Vec<(usize, &str)> {
+        self.remaining_indexes.iter().copied().zip(self.remaining_args.iter().map(String::as_str)).collect()
+    }
+
+    /// Method to retrieve all positional arguments
+    /// with the index of the process argument of each one
+    pub fn get_positional_args_with_index(&self) -> Vec<(usize, &str)> {
+        self.positional_indexes.iter().copied().zip(self.positional_args.iter().map(String::as_str)).collect()
+    }
+
+    /// Method that returns if the help option has been set to true
+    /// using its long form (i.g. --help), so the long help text
+    /// has to be shown in place of the help text if an option has one
+    pub fn is_long_help_requested(&self) -> bool {
+        self.long_help_requested
+    }
+
+    /// Method to retrieve the warnings recorded during the
+    /// parsing phase (i.g. a deprecated option has been used)
+    pub fn get_warnings(&self) -> &Vec<String> {
+        &self.warnings
+    }
+
+    /// Method to show on object that implements
+    /// the std::io::Write trait the recorded warnings,
+    /// a warning per line
+    /// * `writer` - Write trait to show warnings
+    pub fn show_warnings_on(&self, writer: &mut dyn Write) -> IOResult<()> {
+        for warning in &self.warnings {
+            writeln!(writer, "{}", warning)?;
+        }
+        Ok(())
+    }
+
+    /// Method to check if mandatory options
+    /// has at least a value set
+    pub fn check_mandatory_options(&self) -> StdResult<(), CommandLineParserError> {
+        for option in &self.options {
+            if option.mandatory &&  !option.is_set() {
+                return Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(MANDATORY_OPTION_HAS_NOT_SET_ERROR_IDX, &option.get_flags(), "")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Method to check if mandatory options
+    /// has at least a value set, as opposed to check_mandatory_options
+    /// an error is returned for each mandatory option that has not been set
+    pub fn check_all_mandatory_options(&self) -> StdResult<(), Vec<CommandLineParserError>> {
+        let mut errors: Vec<CommandLineParserError> = vec![];
+        for option in &self.options {
+            if option.mandatory &&  !option.is_set() {
+                errors.push(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                    self.errors_catalog.error(MANDATORY_OPTION_HAS_NOT_SET_ERROR_IDX, &option.get_flags(), "")));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Method to ask on the terminal the values of the mandatory options
+    /// that have not been set, values are read from standard input
+    /// and questions are written on standard error.
+    /// Values of secret options are read without echo
+    /// if standard input is a unix terminal, the echo is restored
+    /// after the read (see set_prompt_missing for signals).
+    /// A value that does not respect the option type is asked again,
+    /// error is returned if standard input is closed before all the values are read
+    pub fn prompt_missing(&mut self) -> StdResult<(), CommandLineParserError> {
+        let echo_control = std::io::stdin().is_terminal();
+        self.prompt_missing_with(&mut std::io::stdin().lock(), &mut std::io::stderr().lock(), echo_control)
+    }
+
+    /// Method to ask the values of the mandatory options
+    /// that have not been set using the passed streams,
+    /// it performs the same functionality as the prompt_missing
+    /// method but secret options values are read as the others
+    /// * `input` - BufRead trait to read values
+    /// * `output` - Write trait to write questions
+    pub fn prompt_missing_on(&mut self, input: &mut dyn BufRead, output: &mut dyn Write) -> StdResult<(), CommandLineParserError> {
+        self.prompt_missing_with(input, output, false)
+    }
+
+    // Method that realizes prompt_missing and prompt_missing_on
+    // * `input` - BufRead trait to read values
+    // * `output` - Write trait to write questions
+    // * `echo_control` - true to disable the terminal echo for secret options
+    fn prompt_missing_with(&mut self, input: &mut dyn BufRead, output: &mut dyn Write, echo_control: bool) -> StdResult<(), CommandLineParserError> {
+        for option in self.options.iter_mut() {
+            if !option.mandatory || option.is_set() {
+                continue;
+            }
+            loop {
+                let _ = write!(output, "{}", option.prompt_text());
+                let _ = output.flush();
+                let echo_guard = (echo_control && option.secret).then(EchoGuard::disable_echo);
+                let mut line = String::new();
+                let read = input.read_line(&mut line);
+                if echo_guard.is_some() {
+                    drop(echo_guard);
+                    let _ = writeln!(output);
+                }
+                if !matches!(read, Ok(read_len) if read_len > 0) {
+                    return Err(CommandLineParserError::new(option.get_flags(), option.get_type_name(),
+                        self.errors_catalog.error(MANDATORY_OPTION_HAS_NOT_SET_ERROR_IDX, &option.get_flags(), "")));
+                }
+                let value = line.trim_end_matches(['\r', '\n']);
+                match option.check_value(value) {
+                    Ok(()) => {
+                        option.values.push(value.to_string());
+                        option.value_indexes.push(0);
+                        break;
+                    },
+                    Err(error_idx) => {
+                        let _ = writeln!(output, "{} {}: {}", option.get_type_name(),
+                                            option.get_flags(), self.errors_catalog.error(error_idx, &option.get_flags(), value));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Method to get a mutable reference to an existing option
+    // It returns an option so in case of a not added option
+    // the None value is returned
+    // * `searched_option` - option flag text to be searched
+    fn get_option_mut(&mut self, searched_option: &str) -> Option<&mut CommandLineOption> {
+        let is_long = searched_option.starts_with("--");
+        for option in self.options.iter_mut() {
+            if is_long {
+                if option.has_long_form(&searched_option[2..]) {
+                    return Some(option)
+                }
+            } else if option.has_short_form(searched_option.chars().nth(1).unwrap_or(EMPTY_SHORT_FORM)) {
+                return Some(option);
+            }
+        }
+        None
+    }
+}
+
+// Guard that disables the terminal echo of the standard input
+// while a secret value is read, the previous terminal settings are
+// restored when it is dropped. On non unix platforms it does nothing
+struct EchoGuard {
+    #[cfg(unix)]
+    settings: Option<libc::termios>,
+}
+
+impl EchoGuard {
+    // Associated function that disables the echo
+    // if the standard input is a terminal with echo enabled
+    #[cfg(unix)]
+    fn disable_echo() -> Self {
+        let mut settings = std::mem::MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: tcgetattr initializes settings when it returns 0
+        if 0 != unsafe { libc::tcgetattr(libc::STDIN_FILENO, settings.as_mut_ptr()) } {
+            return Self { settings: None };
+        }
+        let settings = unsafe { settings.assume_init() };
+        if 0 == settings.c_lflag & libc::ECHO {
+            return Self { settings: None };
+        }
+        let mut no_echo_settings = settings;
+        no_echo_settings.c_lflag &= !libc::ECHO;
+        // SAFETY: no_echo_settings is a valid termios read by tcgetattr
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &no_echo_settings) };
+        Self { settings: Some(settings) }
+    }
+
+    #[cfg(not(unix))]
+    fn disable_echo() -> Self {
+        Self {}
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(settings) = self.settings.take() {
+            // SAFETY: settings is a valid termios read by tcgetattr
+            unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &settings) };
+        }
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -1413,7 +2771,8 @@ mod tests {
         let _ = clp.add_integer_option('V', "verbose", false, "level", "set log verbosity level").unwrap();
         let _ = clp.add_short_string_option('c', false, "file path", "configuration file path").unwrap();
         let args = vec!["test_show_help".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
+        let result = clp.parse(&args).unwrap();
+        clp.set_program_name(result.get_program_name());
         clp.show_help();
         let _ = clp.show_help_on(&mut std::io::stderr().lock());
     }
@@ -1423,8 +2782,8 @@ mod tests {
         let mut clp = CommandLineParser::new(None);
         let config_option = clp.add_string_option('c', "config", false, "file path", "application configuration file").unwrap();
         let args = vec!["is_not_set".to_string()];
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(false, clp.is_set(&config_option));
+        let result = clp.parse(&args).unwrap();
+        assert!(!result.is_set(&config_option));
     }
 
     #[test]
@@ -1432,9 +2791,9 @@ mod tests {
         let mut clp = CommandLineParser::new(None);
         let _ = clp.add_string_option('c', "config", true, "file path", "application configuration file").unwrap();
         let args = vec!["is_not_set".to_string()];
-        assert_eq!(Ok(()), clp.parse(&args));
+        let result = clp.parse(&args).unwrap();
         assert_eq!("StringCommandLineOptionType -c/--config: mandatory option has not been set".to_string(), 
-            clp.check_mandatory_options().unwrap_err().to_string());
+            result.check_mandatory_options().unwrap_err().to_string());
     }
 
     #[test]
@@ -1489,8 +2848,8 @@ mod tests {
         let verbosity = clp.add_integer_option('V', "verbose", false, "level", "set log verbosity level").unwrap();
         let _ = clp.add_short_string_option('c', false, "file path", "configuration file path").unwrap();
         let args = vec!["test_sho_help".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        let value = clp.get_value::<i32>(&verbosity);
+        let result = clp.parse(&args).unwrap();
+        let value = result.get_value::<i32>(&verbosity);
         assert_eq!("IntegerCommandLineOptionType -V/--verbose: is not set".to_string(), value.unwrap_err().to_string());
     }
 
@@ -1502,8 +2861,8 @@ mod tests {
         let _ = clp.add_integer_option('V', "verbose", false, "level", "set log verbosity level").unwrap();
         let _ = clp.add_short_string_option('c', false, "file path", "configuration file path").unwrap();
         let args = vec!["test_sho_help".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        let value = clp.get_value::<i32>(&1u64);
+        let result = clp.parse(&args).unwrap();
+        let value = result.get_value::<i32>(&1u64);
         assert_eq!("UndefinedCommandLineOptionType : option identifier not found".to_string(), value.unwrap_err().to_string());
     }
 
@@ -1515,10 +2874,10 @@ mod tests {
         let verbosity = clp.add_integer_option('V', "verbose", false, "level", "set log verbosity level").unwrap();
         let _ = clp.add_short_string_option('c', false, "file path", "configuration file path").unwrap();
         let args = vec!["test_sho_help".to_string(), "-V".to_string(), "-12".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        let value = clp.get_value::<f32>(&verbosity);
+        let result = clp.parse(&args).unwrap();
+        let value = result.get_value::<f32>(&verbosity);
         assert_eq!("IntegerCommandLineOptionType -V/--verbose: is not of the required type".to_string(), value.unwrap_err().to_string());
-        let value = clp.get_value::<i8>(&verbosity);
+        let value = result.get_value::<i8>(&verbosity);
         assert_eq!(-12, value.unwrap());         
     }
 
@@ -1556,9 +2915,9 @@ mod tests {
         let mut clp = CommandLineParser::new(None);
         let version_option = clp.add_version_option("print-out application version").unwrap();
         let args = vec!["test_boolean_option_eq".to_string(), "-v=false".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(true, clp.is_set(&version_option));
-        assert_eq!(Ok(false), clp.get_value::<bool>(&version_option));
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&version_option));
+        assert_eq!(Ok(false), result.get_value::<bool>(&version_option));
     }
     #[test]
     fn test_boolean_option_arg_already_assigned() {
@@ -1575,8 +2934,8 @@ mod tests {
         let mut clp = CommandLineParser::new(None);
         let config_option = clp.add_long_string_option("config", false, "file path", "application configuration file").unwrap();
         let args = vec!["test_long_form_option".to_string(), "--config=goofy.properties".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(String::from("goofy.properties"), clp.get_value::<String>(&config_option).unwrap());
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(String::from("goofy.properties"), result.get_value::<String>(&config_option).unwrap());
     }
 
     #[test]
@@ -1584,8 +2943,8 @@ mod tests {
         let mut clp = CommandLineParser::new(None);
         let help_option = clp.add_help_option("show this help").unwrap();
         let args = vec!["test_boolean_long_form_option".to_string(), "--help=false".to_string()];        
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(Ok(false), clp.get_value::<bool>(&help_option));
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(Ok(false), result.get_value::<bool>(&help_option));
     }
 
     #[test]
@@ -1602,9 +2961,9 @@ mod tests {
                                     "--config".to_string(),
                                     "config3.properties".to_string(),
                                     ];   
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(true, clp.is_set(&config_option));
-        let values: Vec<String> = clp.get_values(&config_option).unwrap();
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&config_option));
+        let values: Vec<String> = result.get_values(&config_option).unwrap();
         assert_eq!(vec!["config1.properties".to_string(), 
                         "config2.properties".to_string(),
                         "config3.properties".to_string()],
@@ -1627,14 +2986,14 @@ mod tests {
                                     "config3.properties".to_string(),
                                     "remaining_args3".to_string(),
                                     ];   
-        assert_eq!(Ok(()), clp.parse(&args));
+        let result = clp.parse(&args).unwrap();
         let remaining_args = vec![
             "remaining_args1".to_string(),
             "remaining_args2".to_string(),
             "remaining_args3".to_string()
         ];
-        assert_eq!(clp.get_remaining_args(), &remaining_args);
-        println!("get_remaining_args(): {:#?}", clp.get_remaining_args());
+        assert_eq!(result.get_remaining_args(), &remaining_args);
+        println!("get_remaining_args(): {:#?}", result.get_remaining_args());
         println!("remaining_args: {:#?}", remaining_args);
     }
 
@@ -1655,21 +3014,21 @@ mod tests {
                                     "--nocapture".to_string(), 
                                     "--test-threads=1".to_string()
                                     ];   
-        assert_eq!(Ok(()), clp.parse(&args));
+        let result = clp.parse(&args).unwrap();
         let remaining_args = vec![
             "remaining_args1".to_string(),
             "remaining_args2".to_string(),
         ];
-        assert_eq!(clp.get_remaining_args(), &remaining_args);
-        println!("get_remaining_args(): {:#?}", clp.get_remaining_args());
+        assert_eq!(result.get_remaining_args(), &remaining_args);
+        println!("get_remaining_args(): {:#?}", result.get_remaining_args());
         println!("remaining_args: {:#?}", remaining_args);
 
         let positional_args = vec![
             "--nocapture".to_string(), 
             "--test-threads=1".to_string()
         ];
-        assert_eq!(clp.get_positional_args(), &positional_args);
-        println!("get_positional_args(): {:#?}", clp.get_positional_args());
+        assert_eq!(result.get_positional_args(), &positional_args);
+        println!("get_positional_args(): {:#?}", result.get_positional_args());
         println!("positional_args: {:#?}", positional_args);
     }
 
@@ -1681,8 +3040,541 @@ mod tests {
                                     "--".to_string(),
                                     "-".to_string()
         ];
-        assert_eq!(Ok(()), clp.parse(&args));
-        assert_eq!(true, clp.is_set(&dashdash_option));
-        assert_eq!("-".to_string(), clp.get_value::<String>(&dashdash_option).unwrap())
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&dashdash_option));
+        assert_eq!("-".to_string(), result.get_value::<String>(&dashdash_option).unwrap())
+    }
+
+    #[test]
+    fn parse_does_not_accumulate_results() {
+        let mut clp = CommandLineParser::new(None);
+        let config_option = clp.add_string_option('c', "config", false, "file path", "application configuration file").unwrap();
+        let first_args = vec!["first".to_string(), "-c".to_string(), "first.properties".to_string(), "remaining".to_string()];
+        let second_args = vec!["second".to_string(), "--config=second.properties".to_string()];
+        let first = clp.parse(&first_args).unwrap();
+        let second = clp.parse(&second_args).unwrap();
+        assert_eq!(Some(vec!["first.properties".to_string()]), first.get_values::<String>(&config_option));
+        assert_eq!(Some(vec!["second.properties".to_string()]), second.get_values::<String>(&config_option));
+        assert_eq!("second", second.get_program_name());
+        assert!(second.get_remaining_args().is_empty());
+    }
+
+    #[test]
+    fn parse_from_several_threads() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_integer_option('V', "verbose", false, "level", "log verbosity level").unwrap();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4).map(|level| {
+                let clp = &clp;
+                scope.spawn(move || {
+                    let args = vec!["threads".to_string(), format!("--verbose={}", level)];
+                    clp.parse(&args).unwrap().get_value::<i32>(&verbose_option).unwrap()
+                })
+            }).collect();
+            for (level, handle) in handles.into_iter().enumerate() {
+                assert_eq!(level as i32, handle.join().unwrap());
+            }
+        });
+    }
+
+    #[test]
+    fn hidden_option_help_text() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_help_option("print-out help menu").unwrap();
+        let _ = clp.add_help_all_option("print-out help menu with hidden options").unwrap();
+        let legacy_option = clp.add_long_string_option("legacy-config", false, "file path", "old configuration file").unwrap();
+        clp.set_option_hidden(&legacy_option, true).unwrap();
+        let help_text = "\
+\t -h/--help    print-out help menu.
+\t--help-all    print-out help menu with hidden options.\n";
+        assert_eq!(clp.get_help_text(), help_text);
+        let help_all_text = "\
+\t      -h/--help             print-out help menu.
+\t     --help-all             print-out help menu with hidden options.
+\t--legacy-config <file path> old configuration file.\n";
+        assert_eq!(clp.get_help_all_text(), help_all_text);
+        let args = vec!["hidden_option_help_text".to_string(), "--legacy-config=old.properties".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(Ok("old.properties".to_string()), result.get_value::<String>(&legacy_option));
+    }
+
+    #[test]
+    fn deprecated_option_warnings() {
+        let mut clp = CommandLineParser::new(None);
+        let output_option = clp.add_long_string_option("output-directory", false, "dir", "output directory").unwrap();
+        let color_option = clp.add_boolean_option('C', "color", false, "colored output").unwrap();
+        clp.set_option_deprecated(&output_option, "--out-dir").unwrap();
+        clp.set_option_deprecated(&color_option, "").unwrap();
+        let args = vec!["deprecated_option_warnings".to_string(),
+                        "--output-directory".to_string(),
+                        "out".to_string(),
+                        "-C".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(&vec!["--output-directory: option is deprecated, use --out-dir".to_string(),
+                         "-C/--color: option is deprecated".to_string()],
+                   result.get_warnings());
+        assert_eq!("UndefinedCommandLineOptionType : option identifier not found".to_string(),
+                   clp.set_option_deprecated(&1u64, "").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn option_aliases() {
+        let mut clp = CommandLineParser::new(None);
+        let version_option = clp.add_version_option("print-out application version").unwrap();
+        let color_option = clp.add_long_boolean_option("color", false, "colored output").unwrap();
+        let out_dir_option = clp.add_long_string_option("out-dir", false, "dir", "output directory").unwrap();
+        clp.add_short_alias(&version_option, 'V').unwrap();
+        clp.add_long_alias(&color_option, "colour").unwrap();
+        clp.add_long_alias(&out_dir_option, "output-directory").unwrap();
+        let args = vec!["option_aliases".to_string(),
+                        "-V".to_string(),
+                        "--colour".to_string(),
+                        "--output-directory=out".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&version_option));
+        assert!(result.is_set(&color_option));
+        assert_eq!(Ok("out".to_string()), result.get_value::<String>(&out_dir_option));
+        assert_eq!("BooleanCommandLineOptionType -v/--version: single character option already exists".to_string(),
+                   clp.add_short_boolean_option('V', false, "verbose").unwrap_err().to_string());
+        assert_eq!("BooleanCommandLineOptionType --color: long form option already exists".to_string(),
+                   clp.add_long_alias(&version_option, "colour").unwrap_err().to_string());
+        clp.set_show_aliases(true);
+        let help_text = "\
+\t             -v/-V/--version       print-out application version.
+\t            --color/--colour       colored output.
+\t--out-dir/--output-directory <dir> output directory.\n";
+        assert_eq!(clp.get_help_text(), help_text);
+    }
+
+    #[test]
+    fn prompt_missing_options() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_integer_option('V', "verbose", true, "level", "log verbosity level").unwrap();
+        let config_option = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+        let password_option = clp.add_long_string_option("password", true, "password", "database password").unwrap();
+        clp.set_option_secret(&password_option, true).unwrap();
+        let args = vec!["prompt_missing_options".to_string(), "-c".to_string(), "app.properties".to_string()];
+        let mut result = clp.parse(&args).unwrap();
+        let mut input = std::io::Cursor::new("high\n3\nsecret\n");
+        let mut output: Vec<u8> = vec![];
+        assert_eq!(Ok(()), result.prompt_missing_on(&mut input, &mut output));
+        assert_eq!("\
+-V/--verbose log verbosity level <level>: \
+IntegerCommandLineOptionType -V/--verbose: is not of the required type
+-V/--verbose log verbosity level <level>: \
+--password database password <password>: ", String::from_utf8(output).unwrap());
+        assert_eq!(Ok(3), result.get_value::<i32>(&verbose_option));
+        assert_eq!(Ok("app.properties".to_string()), result.get_value::<String>(&config_option));
+        assert_eq!(Ok("secret".to_string()), result.get_value::<String>(&password_option));
+        assert_eq!(Ok(()), result.check_mandatory_options());
+    }
+
+    #[test]
+    fn prompt_missing_secret_option() {
+        let mut clp = CommandLineParser::new(None);
+        let password_option = clp.add_long_string_option("password", true, "password", "database password").unwrap();
+        let _ = clp.add_long_string_option("user", true, "name", "database user").unwrap();
+        clp.set_option_secret(&password_option, true).unwrap();
+        let args = vec!["prompt_missing_secret_option".to_string()];
+        let mut result = clp.parse(&args).unwrap();
+        let mut input = std::io::Cursor::new("secret\nadmin\n");
+        let mut output: Vec<u8> = vec![];
+        // with echo control the new line not echoed after a secret value is written
+        assert_eq!(Ok(()), result.prompt_missing_with(&mut input, &mut output, true));
+        assert_eq!("--password database password <password>: \n--user database user <name>: ", String::from_utf8(output).unwrap());
+        assert_eq!(Ok("secret".to_string()), result.get_value::<String>(&password_option));
+        let mut result = clp.parse(&args).unwrap();
+        let mut input = std::io::Cursor::new("secret\nadmin\n");
+        let mut output: Vec<u8> = vec![];
+        assert_eq!(Ok(()), result.prompt_missing_on(&mut input, &mut output));
+        assert_eq!("--password database password <password>: --user database user <name>: ", String::from_utf8(output).unwrap());
+        assert_eq!(Ok("secret".to_string()), result.get_value::<String>(&password_option));
+    }
+
+    #[test]
+    fn prompt_missing_input_closed() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+        let args = vec!["prompt_missing_input_closed".to_string()];
+        let mut result = clp.parse(&args).unwrap();
+        let mut input = std::io::Cursor::new("");
+        let mut output: Vec<u8> = vec![];
+        assert_eq!("StringCommandLineOptionType -c/--config: mandatory option has not been set".to_string(),
+                   result.prompt_missing_on(&mut input, &mut output).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn auto_help_version() {
+        let mut clp = CommandLineParser::new(None);
+        let help_option = clp.add_help_option("print-out help menu").unwrap();
+        let version_option = clp.add_version_option("print-out application version").unwrap();
+        let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+        clp.set_version("auto_help_version 1.0.2");
+        let args = vec!["auto_help_version".to_string(), "--version".to_string(), "--unknown".to_string()];
+        assert_eq!("UndefinedCommandLineOptionType --unknown: option not found".to_string(),
+                   clp.parse(&args).unwrap_err().to_string());
+        let (result, _) = clp.parse_collecting(&args, false);
+        assert!(result.is_set(&version_option));
+        let mut output: Vec<u8> = vec![];
+        assert!(clp.show_requested_info(&result, &mut output, false));
+        assert_eq!("auto_help_version 1.0.2\n", String::from_utf8(output).unwrap());
+        let args = vec!["auto_help_version".to_string(), "-h".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&help_option));
+        let mut output: Vec<u8> = vec![];
+        assert!(clp.show_requested_info(&result, &mut output, false));
+        assert!(String::from_utf8(output).unwrap().starts_with("auto_help_version [OPTIONS] -c <file path>:\n"));
+        let args = vec!["auto_help_version".to_string(), "-c".to_string(), "app.properties".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert!(!clp.show_requested_info(&result, &mut std::io::sink(), false));
+    }
+
+    #[test]
+    fn manual_help_version() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_version_option("print-out application version").unwrap();
+        clp.set_auto_help_version(false);
+        let args = vec!["manual_help_version".to_string(), "--version".to_string(), "--unknown".to_string()];
+        assert_eq!("UndefinedCommandLineOptionType --unknown: option not found".to_string(),
+                   clp.parse(&args).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn parse_all_errors() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_integer_option('V', "verbose", false, "level", "log verbosity level").unwrap();
+        let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+        let _ = clp.add_long_string_option("output", true, "dir", "output directory").unwrap();
+        let _ = clp.add_long_fpoint_option("ratio", false, "ratio", "compression ratio").unwrap();
+        let args = vec!["parse_all_errors".to_string(),
+                        "-V=1.5".to_string(),
+                        "--unknown".to_string(),
+                        "remaining".to_string(),
+                        "--ratio=2.5".to_string(),
+                        "--verbose".to_string()];
+        let errors: Vec<String> = clp.parse_all(&args).unwrap_err().iter().map(|error| error.to_string()).collect();
+        assert_eq!(vec!["IntegerCommandLineOptionType -V/--verbose: cannot set a floating point value to this option type".to_string(),
+                        "UndefinedCommandLineOptionType --unknown: option not found".to_string(),
+                        "IntegerCommandLineOptionType -V/--verbose: missing option argument".to_string(),
+                        "StringCommandLineOptionType -c/--config: mandatory option has not been set".to_string(),
+                        "StringCommandLineOptionType --output: mandatory option has not been set".to_string()],
+                   errors);
+        assert_eq!("IntegerCommandLineOptionType -V/--verbose: cannot set a floating point value to this option type".to_string(),
+                   clp.parse(&args).unwrap_err().to_string());
+        let args = vec!["parse_all_errors".to_string(), "-c".to_string(), "app.properties".to_string(), "--output=out".to_string()];
+        assert!(clp.parse_all(&args).is_ok());
+    }
+
+    #[test]
+    fn unknown_short_options() {
+        let mut clp = CommandLineParser::new(None);
+        let all_option = clp.add_short_boolean_option('a', false, "all files").unwrap();
+        let _ = clp.add_short_string_option('c', false, "file path", "configuration file path").unwrap();
+        let args = vec!["p".to_string(), "-x".to_string(), "-c".to_string(), "f".to_string()];
+        let errors = clp.parse_all(&args).unwrap_err();
+        assert_eq!(1, errors.len());
+        assert_eq!("UndefinedCommandLineOptionType -x: option not found", errors[0].to_string());
+        assert_eq!((Some(1), Some(1..2)), (errors[0].get_arg_index(), errors[0].get_char_range()));
+        let args = vec!["p".to_string(), "-xay".to_string(), "-bc".to_string()];
+        let errors: Vec<(String, Option<usize>, Option<Range<usize>>)> = clp.parse_all(&args).unwrap_err().iter()
+            .map(|error| (error.to_string(), error.get_arg_index(), error.get_char_range())).collect();
+        assert_eq!(vec![("UndefinedCommandLineOptionType -x: option not found".to_string(), Some(1), Some(1..2)),
+                        ("UndefinedCommandLineOptionType -y: option not found".to_string(), Some(1), Some(3..4)),
+                        ("UndefinedCommandLineOptionType -b: option not found".to_string(), Some(2), Some(1..2)),
+                        ("StringCommandLineOptionType -c: missing option argument".to_string(), Some(2), Some(2..3))],
+                   errors);
+        let args = vec!["p".to_string(), "-ax".to_string()];
+        assert_eq!("UndefinedCommandLineOptionType -x: option not found", clp.parse(&args).unwrap_err().to_string());
+        assert!(clp.parse(&["p".to_string(), "-a".to_string()]).unwrap().is_set(&all_option));
+    }
+
+    #[test]
+    fn error_argument_position() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_boolean_option('x', "extract", false, "extract files").unwrap();
+        let _ = clp.add_boolean_option('z', "gzip", false, "gzip compression").unwrap();
+        let _ = clp.add_string_option('f', "file", false, "file path", "archive file").unwrap();
+        let _ = clp.add_integer_option('V', "verbose", false, "level", "log verbosity level").unwrap();
+        let args = vec!["app".to_string(), "-xzf".to_string(), "archive.tgz".to_string(), "-zV".to_string()];
+        let error = clp.parse(&args).unwrap_err();
+        assert_eq!(Some(3), error.get_arg_index());
+        assert_eq!(Some(2..3), error.get_char_range());
+        assert_eq!("\
+IntegerCommandLineOptionType -V/--verbose: missing option argument
+    app -xzf archive.tgz -zV
+                           ^\n", error.render(&args));
+        let args = vec!["app".to_string(), "-V".to_string(), "1".to_string(), "--verbose=1.5".to_string()];
+        let error = clp.parse(&args).unwrap_err();
+        assert_eq!("\
+IntegerCommandLineOptionType -V/--verbose: cannot set a floating point value to this option type
+    app -V 1 --verbose=1.5
+                       ^^^\n", error.render(&args));
+        let args = vec!["app".to_string(), "--unknown=1".to_string()];
+        assert_eq!("\
+UndefinedCommandLineOptionType --unknown=1: option not found
+    app --unknown=1
+        ^^^^^^^^^\n", clp.parse(&args).unwrap_err().render(&args));
+    }
+
+    #[test]
+    fn wrapped_help_text() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_help_option("print-out help menu").unwrap();
+        let _ = clp.add_string_option('c', "config", false, "file path",
+            "configuration file path, when it is not set the application looks for app.properties in the working directory").unwrap();
+        let _ = clp.add_long_string_option("説明", false, "ファイル", "日本語の説明").unwrap();
+        clp.set_help_width(Some(60));
+        let help_text = "\
+\t  -h/--help             print-out help menu.
+\t-c/--config <file path> configuration file path,
+\t                        when it is not set the
+\t                        application looks for
+\t                        app.properties in the
+\t                        working directory.
+\t     --説明 <ファイル>  日本語の説明.\n";
+        assert_eq!(clp.get_help_text(), help_text);
+    }
+
+    #[test]
+    fn colored_output() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_integer_option('V', "verbose", false, "level", "set log verbosity level").unwrap();
+        clp.set_program_name("app");
+        clp.set_color_mode(ColorMode::AlwaysColorMode);
+        let mut output: Vec<u8> = vec![];
+        clp.show_help_on(&mut output).unwrap();
+        assert_eq!("\x1b[1;4mapp [OPTIONS]:\x1b[0m\n\t\x1b[1;32m-V/--verbose\x1b[0m \x1b[36m<level>\x1b[0m set log verbosity level.\n",
+                   String::from_utf8(output).unwrap());
+        clp.set_color_mode(ColorMode::NeverColorMode);
+        let mut output: Vec<u8> = vec![];
+        clp.show_help_on(&mut output).unwrap();
+        assert_eq!(clp.get_help_text(), String::from_utf8(output).unwrap());
+        let args = vec!["app".to_string(), "-V".to_string()];
+        let error = clp.parse(&args).unwrap_err();
+        assert_eq!("\x1b[1;31mIntegerCommandLineOptionType -V/--verbose:\x1b[0m missing option argument\n    app -V\n         \x1b[1;31m^\x1b[0m\n",
+                   error.render_colored(&args));
+        assert!(ColorMode::AlwaysColorMode.is_colored(false));
+        assert!(!ColorMode::NeverColorMode.is_colored(true));
+    }
+
+    #[test]
+    fn help_sections() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+        let _ = clp.add_long_integer_option("jobs", true, "number", "parallel jobs").unwrap();
+        clp.set_program_name("app");
+        clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        assert_eq!("app [OPTIONS] -c <file path> --jobs <number> [-- <arguments>...]", clp.get_usage_text());
+        clp.set_help_width(Some(40));
+        clp.set_about("app copies files using the configuration file rules");
+        clp.set_usage("app [OPTIONS] -c <file path> <file>...");
+        clp.add_example("app -c app.properties a.txt", "copy a.txt");
+        clp.set_epilog("Report bugs to the issue tracker.");
+        let help_text = "\
+app copies files using the configuration
+file rules
+
+app [OPTIONS] -c <file path> <file>...
+\t-c/--config <file path> configuration file
+\t                        path.
+\t     --jobs <number>    parallel jobs.
+
+Examples:
+\tapp -c app.properties a.txt
+\t    copy a.txt
+
+Report bugs to the issue tracker.\n";
+        assert_eq!(help_text, clp.get_help_text());
+    }
+
+    #[test]
+    fn help_categories() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_integer_option('v', "verbose", false, "level", "set log verbosity level").unwrap();
+        let _ = clp.add_help_option("print-out help menu").unwrap();
+        let port_option = clp.add_integer_option('p', "port", false, "port", "server port").unwrap();
+        let host_option = clp.add_string_option('H', "host", false, "host", "server host").unwrap();
+        clp.set_option_category(&verbose_option, "Logging").unwrap();
+        clp.set_option_category(&port_option, "Network").unwrap();
+        clp.set_option_category(&host_option, "Network").unwrap();
+        let help_text = "\
+Logging:
+\t-v/--verbose <level> set log verbosity level.
+
+Options:
+\t   -h/--help         print-out help menu.
+
+Network:
+\t   -p/--port <port>  server port.
+\t   -H/--host <host>  server host.\n";
+        assert_eq!(help_text, clp.get_help_text());
+        clp.set_sort_help(true);
+        clp.set_errors_catalog(ErrorCatalog::parse("heading_Options=Opzioni\nheading_Network=Rete").unwrap());
+        let help_text = "\
+Logging:
+\t-v/--verbose <level> set log verbosity level.
+
+Opzioni:
+\t   -h/--help         print-out help menu.
+
+Rete:
+\t   -H/--host <host>  server host.
+\t   -p/--port <port>  server port.\n";
+        assert_eq!(help_text, clp.get_help_text());
+    }
+
+    #[test]
+    fn short_and_long_help() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_help_option("print-out help menu").unwrap();
+        let config_option = clp.add_string_option('c', "config", false, "file path", "configuration file path").unwrap();
+        clp.set_option_long_help(&config_option,
+            "Configuration file path.\n\nWhen it is not set the application looks for app.properties in the working directory.").unwrap();
+        clp.set_help_width(Some(60));
+        let help_text = "\
+\t  -h/--help             print-out help menu.
+\t-c/--config <file path> configuration file path.\n";
+        assert_eq!(help_text, clp.get_help_text());
+        let long_help_text = "\
+\t-h/--help
+\t    print-out help menu.
+
+\t-c/--config <file path>
+\t    Configuration file path.
+
+\t    When it is not set the application looks for
+\t    app.properties in the working directory.\n";
+        assert_eq!(long_help_text, clp.get_long_help_text());
+        let result = clp.parse(&["app".to_string(), "-h".to_string()]).unwrap();
+        assert!(!result.is_long_help_requested());
+        let mut output: Vec<u8> = vec![];
+        assert!(clp.show_requested_info(&result, &mut output, false));
+        assert_eq!(format!("app [OPTIONS]:\n{}", help_text), String::from_utf8(output).unwrap());
+        let result = clp.parse(&["app".to_string(), "--help".to_string()]).unwrap();
+        assert!(result.is_long_help_requested());
+        let mut output: Vec<u8> = vec![];
+        assert!(clp.show_requested_info(&result, &mut output, false));
+        assert_eq!(format!("app [OPTIONS]:\n{}", long_help_text), String::from_utf8(output).unwrap());
+        let result = clp.parse(&["app".to_string(), "--help=false".to_string()]).unwrap();
+        assert!(!result.is_long_help_requested());
+        assert!(!clp.show_requested_info(&result, &mut std::io::sink(), false));
+
+        clp.set_option_hidden(&config_option, true).unwrap();
+        let result = clp.parse(&["app".to_string(), "--help".to_string()]).unwrap();
+        assert!(result.is_long_help_requested());
+        let mut output: Vec<u8> = vec![];
+        assert!(clp.show_requested_info(&result, &mut output, false));
+        assert_eq!("app [OPTIONS]:\n\t-h/--help    print-out help menu.\n", String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn json_export() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_integer_option('v', "verbose", false, "level", "set \"log\" verbosity").unwrap();
+        let dry_run_option = clp.add_long_boolean_option("dry-run", false, "do not write files").unwrap();
+        clp.set_option_deprecated(&verbose_option, "--log-level").unwrap();
+        clp.set_option_optional_argument(&verbose_option, true).unwrap();
+        clp.set_option_scope(&verbose_option, OptionScope::PreviousSectionScope).unwrap();
+        clp.set_option_section_marker(&dry_run_option, true).unwrap();
+        clp.set_program_name("app");
+        let json = "\
+{
+  \"schema_version\": 1,
+  \"program_name\": \"app\",
+  \"version\": \"\",
+  \"about\": \"\",
+  \"usage\": \"app [OPTIONS]\",
+  \"examples\": [],
+  \"epilog\": \"\",
+  \"parsing_mode\": \"DefaultParsingMode\",
+  \"options\": [
+    {
+      \"id\": \"-v/--verbose\",
+      \"short\": \"v\",
+      \"long\": \"verbose\",
+      \"short_aliases\": [],
+      \"long_aliases\": [],
+      \"type\": \"IntegerCommandLineOptionType\",
+      \"mandatory\": false,
+      \"hidden\": false,
+      \"secret\": false,
+      \"arg_text\": \"level\",
+      \"optional_argument\": true,
+      \"scope\": \"PreviousSectionScope\",
+      \"section_marker\": false,
+      \"help\": \"set \\\"log\\\" verbosity\",
+      \"long_help\": \"\",
+      \"category\": \"\",
+      \"deprecated\": {
+        \"replacement\": \"--log-level\"
+      }
+    },
+    {
+      \"id\": \"--dry-run\",
+      \"short\": null,
+      \"long\": \"dry-run\",
+      \"short_aliases\": [],
+      \"long_aliases\": [],
+      \"type\": \"BooleanCommandLineOptionType\",
+      \"mandatory\": false,
+      \"hidden\": false,
+      \"secret\": false,
+      \"arg_text\": \"\",
+      \"optional_argument\": false,
+      \"scope\": \"GlobalScope\",
+      \"section_marker\": true,
+      \"help\": \"do not write files\",
+      \"long_help\": \"\",
+      \"category\": \"\",
+      \"deprecated\": null
+    }
+  ]
+}
+";
+        assert_eq!(json, clp.to_json());
+    }
+
+    #[test]
+    fn posix_parsing_mode() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_boolean_option('v', "verbose", false, "verbose output").unwrap();
+        clp.set_parsing_mode(ParsingMode::PosixParsingMode);
+        let args: Vec<String> = ["nice", "-v", "make", "-j", "4", "--", "-v"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(1, result.get_values::<bool>(&verbose_option).unwrap().len());
+        assert!(result.get_remaining_args().is_empty());
+        assert_eq!(&vec!["make".to_string(), "-j".to_string(), "4".to_string(), "--".to_string(), "-v".to_string()],
+                   result.get_positional_args());
+        let args: Vec<String> = ["nice", "-v", "--", "make"].iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(&vec!["make".to_string()], clp.parse(&args).unwrap().get_positional_args());
+        clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        assert!(ParsingMode::PositionalArgumentsMode == clp.get_parsing_mode_for(true));
+        clp.set_posixly_correct(true);
+        assert!(ParsingMode::PosixParsingMode == clp.get_parsing_mode_for(true));
+        assert!(ParsingMode::PositionalArgumentsMode == clp.get_parsing_mode_for(false));
+    }
+
+    #[test]
+    fn values_order() {
+        let mut clp = CommandLineParser::new(None);
+        let include_option = clp.add_long_string_option("include", false, "pattern", "files to include").unwrap();
+        let exclude_option = clp.add_long_string_option("exclude", false, "pattern", "files to exclude").unwrap();
+        let verbose_option = clp.add_short_boolean_option('v', false, "verbose output").unwrap();
+        let args: Vec<String> = ["find", "--include", "a", "-v", "--exclude=b", "--include", "c"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(vec![(2, "a".to_string()), (6, "c".to_string())],
+                   result.get_values_with_index::<String>(&include_option).unwrap());
+        assert_eq!(vec![(4, "b".to_string())], result.get_values_with_index::<String>(&exclude_option).unwrap());
+        assert_eq!(&vec![(1, include_option), (3, verbose_option), (4, exclude_option), (5, include_option)],
+                   result.get_options_order());
+
+        clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode);
+        let args: Vec<String> = ["find", "--include", "a", "dir1", "-v", "dir2", "--", "-v"].iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(vec![(3, "dir1"), (5, "dir2")], result.get_remaining_args_with_index());
+        assert_eq!(vec![(7, "-v")], result.get_positional_args_with_index());
+        let (value_index, value) = result.get_values_with_index::<String>(&include_option).unwrap().remove(0);
+        let following_arg = result.get_remaining_args_with_index().into_iter().find(|(arg_index, _)| *arg_index > value_index);
+        assert_eq!(("a", Some((3, "dir1"))), (value.as_str(), following_arg));
     }
 }
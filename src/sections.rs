@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Result};
+use std::result::Result as StdResult;
+use std::str::FromStr;
+
+use crate::{CommandLineParser, CommandLineParserError, ParseResult};
+
+/// Section a command line option value belongs to, sections are started by
+/// the section marker options (see CommandLineParser::set_option_section_marker)
+/// and by the remaining arguments (arguments not related to an option)
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum OptionScope {
+    /// The option is not attached to a section (default)
+    GlobalScope,
+    /// The option is attached to the section started after it
+    /// (i.g. -ss 10 -i a.mp4)
+    NextSectionScope,
+    /// The option is attached to the section started before it
+    /// (i.g. -i a.mp4 -ss 10)
+    PreviousSectionScope,
+}
+
+/// Display trait of OptionScope
+impl Display for OptionScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Process argument found by the parsing phase used to build the sections
+enum SectionItem<'a> {
+    // Section marker option with its value or remaining argument
+    Anchor(Option<u64>, &'a str),
+    // Scoped option with its value
+    Scoped(u64, OptionScope, &'a str),
+}
+
+/// Section of a command line, started by a section marker option
+/// or by a remaining argument, with the values of the scoped options
+/// attached to it (see ParseResult::get_sections)
+#[derive(PartialEq, Clone, Debug)]
+pub struct Section {
+    marker: Option<u64>,
+    anchor: String,
+    arg_index: usize,
+    values: Vec<(u64, usize, String)>,
+}
+
+impl Section {
+    /// Method to retrieve the identifier of the section marker option
+    /// that started the section, None if a remaining argument started it
+    pub fn get_marker(&self) -> Option<u64> {
+        self.marker
+    }
+
+    /// Method to retrieve the value of the section marker option
+    /// or the remaining argument that started the section
+    /// (i.g. a.mp4 for -i a.mp4)
+    pub fn get_anchor(&self) -> &str {
+        &self.anchor
+    }
+
+    /// Method to retrieve the index of the process argument
+    /// of the section marker flag or of the remaining argument
+    pub fn get_arg_index(&self) -> usize {
+        self.arg_index
+    }
+
+    /// Method to check if a scoped command line option is set in the section
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn is_set(&self, option_hash: &u64) -> bool {
+        self.values.iter().any(|(hash, _, _)| hash == option_hash)
+    }
+
+    /// Generic method to get the first value of a scoped command line option
+    /// in the section, None if the option is not set in the section
+    /// or the value is not of the required type &ltT&gt
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn get_value<T: FromStr>(&self, option_hash: &u64) -> Option<T> {
+        self.values.iter()
+            .find(|(hash, _, _)| hash == option_hash)
+            .and_then(|(_, _, value)| value.parse::<T>().ok())
+    }
+
+    /// Generic method to get all values of a scoped command line option
+    /// in the section, None if the option is not set in the section
+    /// or a value is not of the required type &ltT&gt
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    pub fn get_values<T: FromStr>(&self, option_hash: &u64) -> Option<Vec<T>> {
+        let values: Vec<T> = self.values.iter()
+                                .filter(|(hash, _, _)| hash == option_hash)
+                                .map(|(_, _, value)| value.parse::<T>().ok())
+                                .collect::<Option<Vec<T>>>()?;
+        if !values.is_empty() {
+            Some(values)
+        } else {
+            None
+        }
+    }
+
+    /// Method to retrieve the scoped options set in the section
+    /// in the process arguments order, each one with the index of the
+    /// process argument of its flag and its identifier
+    pub fn get_options_order(&self) -> Vec<(usize, u64)> {
+        self.values.iter().map(|(hash, arg_index, _)| (*arg_index, *hash)).collect()
+    }
+}
+
+impl CommandLineParser {
+    /// Method to set the section a command line option value belongs to,
+    /// a scoped option is still returned by ParseResult::get_value and
+    /// get_values, ParseResult::get_sections returns each value in its section
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `scope` - OptionScope::NextSectionScope or OptionScope::PreviousSectionScope
+    ///   to attach the option to a section, OptionScope::GlobalScope otherwise
+    pub fn set_option_scope(&mut self, option_hash: &u64, scope: OptionScope) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.scope = scope;
+        Ok(())
+    }
+
+    /// Method to make a command line option a section marker,
+    /// each time it is found a new section is started (i.g. -i of an ffmpeg
+    /// like tool), a section marker is never attached to a section
+    /// * `option_hash` - command line option identifier returned by
+    ///   an add_* method
+    /// * `section_marker` - true to start a section at each occurrence
+    pub fn set_option_section_marker(&mut self, option_hash: &u64, section_marker: bool) -> StdResult<(), CommandLineParserError> {
+        let option = self.get_option_by_hash_mut(option_hash)?;
+        option.section_marker = section_marker;
+        Ok(())
+    }
+}
+
+impl ParseResult {
+    /// Method to retrieve the sections of the command line in the process
+    /// arguments order: a section is started by each section marker option
+    /// and by each remaining argument, the values of the options scoped by
+    /// set_option_scope are attached to the section that follows or precedes
+    /// them; values without such a section (i.g. a previous scoped option
+    /// before the first section) are not in any section
+    /// # Examples
+    /// ```
+    /// use rsclp::{CommandLineParser, OptionScope};
+    ///
+    /// fn main() {
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     let input_option = clp.add_short_string_option('i', false, "file", "input file").unwrap();
+    ///     let seek_option = clp.add_long_integer_option("ss", false, "seconds", "input start position").unwrap();
+    ///     clp.set_option_section_marker(&input_option, true).unwrap();
+    ///     clp.set_option_scope(&seek_option, OptionScope::PreviousSectionScope).unwrap();
+    ///     let args: Vec<String> = ["media", "-i", "a.mp4", "--ss", "10", "-i", "b.mp4", "--ss", "20"].iter().map(|arg| arg.to_string()).collect();
+    ///     let sections = clp.parse(&args).unwrap().get_sections();
+    ///     assert_eq!("a.mp4", sections[0].get_anchor());
+    ///     assert_eq!(Some(10), sections[0].get_value::<i64>(&seek_option));
+    ///     assert_eq!("b.mp4", sections[1].get_anchor());
+    ///     assert_eq!(Some(20), sections[1].get_value::<i64>(&seek_option));
+    /// }
+    /// ```
+    pub fn get_sections(&self) -> Vec<Section> {
+        let mut items: Vec<(usize, SectionItem)> = vec![];
+        let mut found_values: HashMap<u64, usize> = HashMap::new();
+        for (arg_index, option_hash) in &self.occurrences {
+            let Some(option) = self.options.iter().find(|option| *option_hash == option.calculate_hash()) else {
+                continue;
+            };
+            let value_idx = found_values.entry(*option_hash).or_insert(0);
+            let value = option.values.get(*value_idx);
+            *value_idx += 1;
+            match value {
+                Some(value) if option.section_marker => items.push((*arg_index, SectionItem::Anchor(Some(*option_hash), value))),
+                Some(value) if OptionScope::GlobalScope != option.scope => items.push((*arg_index, SectionItem::Scoped(*option_hash, option.scope, value))),
+                _ => {}
+            }
+        }
+        for (arg, arg_index) in self.remaining_args.iter().zip(&self.remaining_indexes) {
+            items.push((*arg_index, SectionItem::Anchor(None, arg)));
+        }
+        items.sort_by_key(|(arg_index, _)| *arg_index);
+
+        let mut sections: Vec<Section> = vec![];
+        let mut scoped: Vec<(usize, OptionScope, u64, usize, &str)> = vec![];
+        for (arg_index, item) in items {
+            match item {
+                SectionItem::Anchor(marker, anchor) => sections.push(Section {
+                    marker,
+                    anchor: anchor.to_string(),
+                    arg_index,
+                    values: vec![]
+                }),
+                SectionItem::Scoped(option_hash, scope, value) => scoped.push((sections.len(), scope, option_hash, arg_index, value))
+            }
+        }
+        for (previous_sections, scope, option_hash, arg_index, value) in scoped {
+            let section_idx = match scope {
+                OptionScope::NextSectionScope => Some(previous_sections),
+                OptionScope::PreviousSectionScope => previous_sections.checked_sub(1),
+                OptionScope::GlobalScope => None
+            };
+            if let Some(section) = section_idx.and_then(|section_idx| sections.get_mut(section_idx)) {
+                section.values.push((option_hash, arg_index, value.to_string()));
+            }
+        }
+        sections
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoped_sections() {
+        let mut clp = CommandLineParser::new(None);
+        let input_option = clp.add_short_string_option('i', false, "file", "input file").unwrap();
+        let format_option = clp.add_short_string_option('f', false, "format", "input or output format").unwrap();
+        let seek_option = clp.add_long_integer_option("ss", false, "seconds", "input start position").unwrap();
+        let overwrite_option = clp.add_short_boolean_option('y', false, "overwrite output files").unwrap();
+        clp.set_option_section_marker(&input_option, true).unwrap();
+        clp.set_option_scope(&format_option, OptionScope::NextSectionScope).unwrap();
+        clp.set_option_scope(&seek_option, OptionScope::PreviousSectionScope).unwrap();
+        let args: Vec<String> = ["media", "--ss", "5", "-y", "-f", "mp4", "-i", "a", "--ss", "10", "--ss", "15", "-i", "b", "-f", "mkv", "out.mkv"]
+                                    .iter().map(|arg| arg.to_string()).collect();
+        let result = clp.parse(&args).unwrap();
+        assert!(result.is_set(&overwrite_option));
+        assert_eq!(Some(vec![5, 10, 15]), result.get_values::<i64>(&seek_option));
+        let sections = result.get_sections();
+        assert_eq!(3, sections.len());
+        assert_eq!((Some(input_option), "a", 6), (sections[0].get_marker(), sections[0].get_anchor(), sections[0].get_arg_index()));
+        assert_eq!(Some("mp4".to_string()), sections[0].get_value::<String>(&format_option));
+        assert_eq!(Some(vec![10, 15]), sections[0].get_values::<i64>(&seek_option));
+        assert_eq!(vec![(4, format_option), (8, seek_option), (10, seek_option)], sections[0].get_options_order());
+        assert_eq!((Some(input_option), "b"), (sections[1].get_marker(), sections[1].get_anchor()));
+        assert!(!sections[1].is_set(&format_option) && !sections[1].is_set(&seek_option));
+        assert_eq!((None, "out.mkv", 16), (sections[2].get_marker(), sections[2].get_anchor(), sections[2].get_arg_index()));
+        assert_eq!(Some("mkv".to_string()), sections[2].get_value::<String>(&format_option));
+        assert!(!sections[2].is_set(&overwrite_option));
+    }
+}
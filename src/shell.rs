@@ -0,0 +1,185 @@
+use std::result::Result as StdResult;
+
+use crate::{CommandLineParser, CommandLineParserError, ParseResult, COMMAND_LINE_OPTION_TYPE_UNDEFINED,
+            MISSING_ESCAPED_CHARACTER_ERROR_KEY, UNTERMINATED_QUOTE_ERROR_KEY};
+
+// Characters written without quotes by quote_command_line
+const SAFE_CHARACTERS: &str = "_@%+=:,./-";
+
+// Characters escaped by a backslash inside double quotes
+const DOUBLE_QUOTE_ESCAPED_CHARACTERS: [char; 5] = ['\\', '"', '$', '`', '\n'];
+
+// Function that returns the shell quoted text of an argument,
+// arguments made of safe characters only are written as they are
+// * `arg` - argument to quote
+fn quote_arg(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(|character| character.is_ascii_alphanumeric() || SAFE_CHARACTERS.contains(character)) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+impl CommandLineParser {
+    // Method that returns the error of a command line text
+    // * `error_key` - error message key
+    // * `character` - character that caused the error
+    // * `position` - index of the character in the command line text
+    fn command_line_error(&self, error_key: &str, character: char, position: usize) -> CommandLineParserError {
+        CommandLineParserError {
+            char_range: Some(position..position + 1),
+            in_text: true,
+            ..CommandLineParserError::new(character.to_string(), COMMAND_LINE_OPTION_TYPE_UNDEFINED.to_string(),
+                self.errors_catalog.get_message(error_key, &character.to_string(), &(position + 1).to_string()))
+        }
+    }
+
+    /// Method to split a command line text into arguments as a POSIX shell does:
+    /// arguments are separated by blanks, single quotes keep all characters
+    /// as they are, double quotes keep all characters but the \\, \", \$, \`
+    /// escapes, a backslash outside quotes keeps the following character,
+    /// a backslash followed by a new line joins two lines and a # starting
+    /// an argument starts a comment up to the end of the line.
+    /// Variables, globs and other shell expansions are not done.
+    /// An error is returned for an unterminated quote or a backslash
+    /// at the end of the text, with the character range of the quote or backslash
+    /// in the text (see CommandLineParserError::get_text_range)
+    /// * `command_line` - command line text (i.g. --name 'hello world' -v)
+    pub fn split_command_line(&self, command_line: &str) -> StdResult<Vec<String>, CommandLineParserError> {
+        let chars: Vec<char> = command_line.chars().collect();
+        let mut args: Vec<String> = vec![];
+        let mut arg = String::new();
+        let mut in_arg = false;
+        let mut idx: usize = 0;
+        while idx < chars.len() {
+            match chars[idx] {
+                ' ' | '\t' | '\n' => {
+                    if in_arg {
+                        args.push(std::mem::take(&mut arg));
+                        in_arg = false;
+                    }
+                },
+                '#' if !in_arg => {
+                    while idx + 1 < chars.len() && '\n' != chars[idx + 1] {
+                        idx += 1;
+                    }
+                },
+                '\'' => {
+                    let start = idx;
+                    in_arg = true;
+                    idx += 1;
+                    while '\'' != *chars.get(idx).ok_or_else(|| self.command_line_error(UNTERMINATED_QUOTE_ERROR_KEY, '\'', start))? {
+                        arg.push(chars[idx]);
+                        idx += 1;
+                    }
+                },
+                '"' => {
+                    let start = idx;
+                    in_arg = true;
+                    idx += 1;
+                    while '"' != *chars.get(idx).ok_or_else(|| self.command_line_error(UNTERMINATED_QUOTE_ERROR_KEY, '"', start))? {
+                        if '\\' == chars[idx] && chars.get(idx + 1).is_some_and(|next| DOUBLE_QUOTE_ESCAPED_CHARACTERS.contains(next)) {
+                            idx += 1;
+                            if '\n' != chars[idx] {
+                                arg.push(chars[idx]);
+                            }
+                        } else {
+                            arg.push(chars[idx]);
+                        }
+                        idx += 1;
+                    }
+                },
+                '\\' => {
+                    match chars.get(idx + 1) {
+                        Some('\n') => {},
+                        Some(escaped) => {
+                            in_arg = true;
+                            arg.push(*escaped);
+                        },
+                        None => return Err(self.command_line_error(MISSING_ESCAPED_CHARACTER_ERROR_KEY, '\\', idx))
+                    }
+                    idx += 1;
+                },
+                character => {
+                    in_arg = true;
+                    arg.push(character);
+                }
+            }
+            idx += 1;
+        }
+        if in_arg {
+            args.push(arg);
+        }
+        Ok(args)
+    }
+
+    /// Method that realizes the parsing of a command line text,
+    /// the text is split by split_command_line and does not contain the
+    /// program name, the parser program name is used (see set_program_name)
+    /// * `command_line` - command line text (i.g. --name 'hello world' -v)
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     let name_option = clp.add_long_string_option("name", false, "text", "greeting name").unwrap();
+    ///     let verbose_option = clp.add_short_boolean_option('v', false, "verbose output").unwrap();
+    ///     let result = clp.parse_str("--name 'hello world' -v").unwrap();
+    ///     assert_eq!("hello world", result.get_value::<String>(&name_option).unwrap());
+    ///     assert!(result.is_set(&verbose_option));
+    ///     assert_eq!("UndefinedCommandLineOptionType \": unterminated quote at character 8",
+    ///                clp.parse_str("--name \"hello").unwrap_err().to_string());
+    /// }
+    /// ```
+    pub fn parse_str(&self, command_line: &str) -> StdResult<ParseResult, CommandLineParserError> {
+        let mut process_args: Vec<String> = vec![self.program_name.clone()];
+        process_args.extend(self.split_command_line(command_line)?);
+        self.parse(&process_args)
+    }
+
+    /// Associated function that returns the command line text of arguments,
+    /// quoted so that a POSIX shell (or split_command_line) splits it
+    /// back into the same arguments (i.g. for logs): arguments with characters
+    /// other than letters, digits and _@%+=:,./- are enclosed in single quotes
+    /// * `args` - arguments to quote
+    pub fn quote_command_line(args: &[String]) -> String {
+        args.iter().map(|arg| quote_arg(arg)).collect::<Vec<String>>().join(" ")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_command_lines() {
+        let clp = CommandLineParser::new(None);
+        let split = |command_line: &str| clp.split_command_line(command_line).unwrap();
+        assert_eq!(vec!["--name", "hello world", "-v"], split("  --name 'hello world'\t-v "));
+        assert_eq!(vec!["a\"b", "c d", "$HOME\\x", "", "it's"], split(r#"a\"b c\ d "\$HOME\x" '' it\'s"#));
+        assert_eq!(vec!["a", "bc", "d#e"], split("a # comment\nb\\\nc d#e # end"));
+        assert_eq!(vec!["one two", "'x'"], split("\"one \"'two' \"'x'\""));
+        assert!(split(" \n# only a comment").is_empty());
+
+        let error = clp.split_command_line("echo 'it").unwrap_err();
+        assert_eq!("UndefinedCommandLineOptionType ': unterminated quote at character 6", error.to_string());
+        assert_eq!((None, None, Some(5..6)), (error.get_arg_index(), error.get_char_range(), error.get_text_range()));
+        assert_eq!("UndefinedCommandLineOptionType ': unterminated quote at character 6\n    echo 'it\n         ^\n",
+                   error.render(&["echo 'it".to_string()]));
+        let command_line = "ls \\\n  -l \"a";
+        let error = clp.split_command_line(command_line).unwrap_err();
+        assert_eq!("UndefinedCommandLineOptionType \": unterminated quote at character 11\n      -l \"a\n         ^\n",
+                   error.render(&[command_line.to_string()]));
+        assert_eq!("UndefinedCommandLineOptionType \\: missing escaped character at character 4",
+                   clp.split_command_line("abc\\").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn quote_command_lines() {
+        let args: Vec<String> = ["ls", "-l", "my file", "it's", "", "a=b/c.txt", "$x;rm"].iter().map(|arg| arg.to_string()).collect();
+        let command_line = CommandLineParser::quote_command_line(&args);
+        assert_eq!("ls -l 'my file' 'it'\\''s' '' a=b/c.txt '$x;rm'", command_line);
+        assert_eq!(args, CommandLineParser::new(None).split_command_line(&command_line).unwrap());
+    }
+}
@@ -0,0 +1,666 @@
+use std::collections::HashMap;
+use std::io::{Error as IOError, ErrorKind, Result as IOResult};
+use std::path::Path;
+
+use crate::json::{self, JsonValue};
+use crate::{CommandLineParser, CommandLineParserError, OptionScope, ParsingMode, COMMAND_LINE_OPTION_TYPE_BOOLEAN,
+            COMMAND_LINE_OPTION_TYPE_FPOINT, COMMAND_LINE_OPTION_TYPE_INTEGER, COMMAND_LINE_OPTION_TYPE_STRING, JSON_SCHEMA_VERSION};
+
+// Section headers of a specification
+const PROGRAM_SECTION: &str = "program";
+const OPTION_SECTION: &str = "option";
+
+// Option types of a specification
+const BOOLEAN_SPEC_TYPE: &str = "boolean";
+const INTEGER_SPEC_TYPE: &str = "integer";
+const FPOINT_SPEC_TYPE: &str = "fpoint";
+const STRING_SPEC_TYPE: &str = "string";
+pub(crate) const HELP_SPEC_TYPE: &str = "help";
+pub(crate) const HELP_ALL_SPEC_TYPE: &str = "help_all";
+pub(crate) const VERSION_SPEC_TYPE: &str = "version";
+
+// Parsing modes of a specification
+const DEFAULT_SPEC_PARSING_MODE: &str = "default";
+const POSITIONAL_SPEC_PARSING_MODE: &str = "positional";
+const POSIX_SPEC_PARSING_MODE: &str = "posix";
+
+// Option scopes of a specification
+const GLOBAL_SPEC_SCOPE: &str = "global";
+const NEXT_SPEC_SCOPE: &str = "next";
+const PREVIOUS_SPEC_SCOPE: &str = "previous";
+
+// Separator of the aliases lists
+const SPEC_LIST_SEPARATOR: char = ',';
+
+// Function that returns the InvalidData error of a specification location
+// * `location` - location in the specification (i.g. line 3 or options[1])
+// * `message` - error message
+fn location_error(location: &str, message: &str) -> IOError {
+    IOError::new(ErrorKind::InvalidData, format!("{}: {}", location, message))
+}
+
+// Function that returns the InvalidData error of a specification line
+// * `line` - specification line number (starting from 1)
+// * `message` - error message
+pub(crate) fn spec_error(line: usize, message: &str) -> IOError {
+    location_error(&line_location(line), message)
+}
+
+// Function that returns the location of a specification line
+// * `line` - specification line number (starting from 1)
+fn line_location(line: usize) -> String {
+    format!("line {}", line)
+}
+
+// Function that returns a specification value with
+// the \n, \t and \\ escape sequences replaced
+// * `value` - value as written in the specification
+fn unescape(value: &str) -> String {
+    let mut result = String::new();
+    let mut chars = value.chars();
+    while let Some(character) = chars.next() {
+        if '\\' != character {
+            result.push(character);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\')
+        }
+    }
+    result
+}
+
+// Function that converts a specification value to a boolean
+// * `location` - location of the value
+// * `key` - key of the value
+// * `value` - value to convert (true or false)
+fn parse_bool(location: &str, key: &str, value: &str) -> IOResult<bool> {
+    value.parse::<bool>().map_err(|_| location_error(location, &format!("'{}' value of {} is not true or false", value, key)))
+}
+
+// Function that converts a specification value to a single character flag
+// * `location` - location of the value
+// * `key` - key of the value
+// * `value` - value to convert
+fn parse_char(location: &str, key: &str, value: &str) -> IOResult<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(character), None) => Ok(character),
+        _ => Err(location_error(location, &format!("'{}' value of {} is not a single character", value, key)))
+    }
+}
+
+// Function that returns the members of a JSON specification object
+// * `location` - location of the value
+// * `value` - JSON value
+fn json_members<'a>(location: &str, value: &'a JsonValue) -> IOResult<&'a Vec<(String, JsonValue)>> {
+    match value {
+        JsonValue::Object(members) => Ok(members),
+        _ => Err(location_error(location, "value is not an object"))
+    }
+}
+
+// Function that returns the elements of a JSON specification array
+// * `location` - location of the value
+// * `key` - key of the value
+// * `value` - JSON value
+fn json_array<'a>(location: &str, key: &str, value: &'a JsonValue) -> IOResult<&'a Vec<JsonValue>> {
+    match value {
+        JsonValue::Array(elements) => Ok(elements),
+        _ => Err(location_error(location, &format!("value of {} is not an array", key)))
+    }
+}
+
+// Function that returns the text of a JSON specification string
+// * `location` - location of the value
+// * `key` - key of the value
+// * `value` - JSON value
+fn json_string<'a>(location: &str, key: &str, value: &'a JsonValue) -> IOResult<&'a str> {
+    match value {
+        JsonValue::String(text) => Ok(text),
+        _ => Err(location_error(location, &format!("value of {} is not a string", key)))
+    }
+}
+
+// Function that returns the value of a JSON specification boolean
+// * `location` - location of the value
+// * `key` - key of the value
+// * `value` - JSON value
+fn json_bool(location: &str, key: &str, value: &JsonValue) -> IOResult<bool> {
+    match value {
+        JsonValue::Bool(value) => Ok(*value),
+        _ => Err(location_error(location, &format!("value of {} is not true or false", key)))
+    }
+}
+
+// Command line option as written in a specification [option <name>] section
+#[derive(Default)]
+struct OptionSpec {
+    name: String,
+    location: String,
+    typ: String,
+    short_form_option: Option<char>,
+    long_form_option: String,
+    mandatory: bool,
+    arg_text: String,
+    help_text: String,
+    long_help: String,
+    category: String,
+    hidden: bool,
+    secret: bool,
+    deprecated: Option<String>,
+    optional_argument: bool,
+    scope: Option<OptionScope>,
+    section_marker: bool,
+    short_aliases: Vec<(String, char)>,
+    long_aliases: Vec<(String, String)>,
+}
+
+impl OptionSpec {
+    // Method to set an option attribute from a key = value line
+    // * `line` - specification line number
+    // * `key` - attribute name
+    // * `value` - attribute value
+    fn set(&mut self, line: usize, key: &str, value: &str) -> IOResult<()> {
+        let location = line_location(line);
+        match key {
+            "type" => self.typ = value.to_string(),
+            "short" => self.short_form_option = Some(parse_char(&location, key, value)?),
+            "long" => self.long_form_option = value.to_string(),
+            "mandatory" => self.mandatory = parse_bool(&location, key, value)?,
+            "arg_text" => self.arg_text = unescape(value),
+            "help" => self.help_text = unescape(value),
+            "long_help" => self.long_help = unescape(value),
+            "category" => self.category = unescape(value),
+            "hidden" => self.hidden = parse_bool(&location, key, value)?,
+            "secret" => self.secret = parse_bool(&location, key, value)?,
+            "deprecated" => self.deprecated = Some(unescape(value)),
+            "optional_argument" => self.optional_argument = parse_bool(&location, key, value)?,
+            "scope" => self.scope = Some(match value {
+                GLOBAL_SPEC_SCOPE => OptionScope::GlobalScope,
+                NEXT_SPEC_SCOPE => OptionScope::NextSectionScope,
+                PREVIOUS_SPEC_SCOPE => OptionScope::PreviousSectionScope,
+                _ => return Err(location_error(&location, &format!("unknown scope '{}'", value)))
+            }),
+            "section_marker" => self.section_marker = parse_bool(&location, key, value)?,
+            "short_aliases" => {
+                for alias in value.split(SPEC_LIST_SEPARATOR).map(str::trim).filter(|alias| !alias.is_empty()) {
+                    self.short_aliases.push((location.clone(), parse_char(&location, key, alias)?));
+                }
+            },
+            "long_aliases" => {
+                for alias in value.split(SPEC_LIST_SEPARATOR).map(str::trim).filter(|alias| !alias.is_empty()) {
+                    self.long_aliases.push((location.clone(), alias.to_string()));
+                }
+            },
+            _ => return Err(location_error(&location, &format!("unknown option key '{}'", key)))
+        }
+        Ok(())
+    }
+
+    // Associated function to create an option from an object
+    // of the options array of a JSON specification (see CommandLineParser::to_json),
+    // the option name is its id
+    // * `location` - location of the object (i.g. options[1])
+    // * `value` - JSON object
+    fn from_json(location: &str, value: &JsonValue) -> IOResult<Self> {
+        let mut option_spec = OptionSpec { location: location.to_string(), ..Default::default() };
+        for (key, value) in json_members(location, value)? {
+            let key = key.as_str();
+            match (key, value) {
+                ("id", value) => option_spec.name = json_string(location, key, value)?.to_string(),
+                ("type", value) => option_spec.typ = match json_string(location, key, value)? {
+                    COMMAND_LINE_OPTION_TYPE_BOOLEAN => BOOLEAN_SPEC_TYPE.to_string(),
+                    COMMAND_LINE_OPTION_TYPE_INTEGER => INTEGER_SPEC_TYPE.to_string(),
+                    COMMAND_LINE_OPTION_TYPE_FPOINT => FPOINT_SPEC_TYPE.to_string(),
+                    COMMAND_LINE_OPTION_TYPE_STRING => STRING_SPEC_TYPE.to_string(),
+                    typ @ (HELP_SPEC_TYPE | HELP_ALL_SPEC_TYPE | VERSION_SPEC_TYPE) => typ.to_string(),
+                    typ => return Err(location_error(location, &format!("unknown type '{}'", typ)))
+                },
+                ("short", JsonValue::Null) | ("long", JsonValue::Null) | ("deprecated", JsonValue::Null) => {},
+                ("short", value) => option_spec.short_form_option = Some(parse_char(location, key, json_string(location, key, value)?)?),
+                ("long", value) => option_spec.long_form_option = json_string(location, key, value)?.to_string(),
+                ("short_aliases", value) => {
+                    for alias in json_array(location, key, value)? {
+                        let alias = parse_char(location, key, json_string(location, key, alias)?)?;
+                        option_spec.short_aliases.push((location.to_string(), alias));
+                    }
+                },
+                ("long_aliases", value) => {
+                    for alias in json_array(location, key, value)? {
+                        option_spec.long_aliases.push((location.to_string(), json_string(location, key, alias)?.to_string()));
+                    }
+                },
+                ("mandatory", value) => option_spec.mandatory = json_bool(location, key, value)?,
+                ("hidden", value) => option_spec.hidden = json_bool(location, key, value)?,
+                ("secret", value) => option_spec.secret = json_bool(location, key, value)?,
+                ("optional_argument", value) => option_spec.optional_argument = json_bool(location, key, value)?,
+                ("section_marker", value) => option_spec.section_marker = json_bool(location, key, value)?,
+                ("arg_text", value) => option_spec.arg_text = json_string(location, key, value)?.to_string(),
+                ("help", value) => option_spec.help_text = json_string(location, key, value)?.to_string(),
+                ("long_help", value) => option_spec.long_help = json_string(location, key, value)?.to_string(),
+                ("category", value) => option_spec.category = json_string(location, key, value)?.to_string(),
+                ("scope", value) => {
+                    let scope = json_string(location, key, value)?;
+                    option_spec.scope = Some([OptionScope::GlobalScope, OptionScope::NextSectionScope, OptionScope::PreviousSectionScope]
+                        .into_iter()
+                        .find(|option_scope| option_scope.to_string() == scope)
+                        .ok_or_else(|| location_error(location, &format!("unknown scope '{}'", scope)))?);
+                },
+                ("deprecated", value) => {
+                    let mut replacement = String::new();
+                    for (key, value) in json_members(location, value)? {
+                        match key.as_str() {
+                            "replacement" => replacement = json_string(location, key, value)?.to_string(),
+                            _ => return Err(location_error(location, &format!("unknown deprecated key '{}'", key)))
+                        }
+                    }
+                    option_spec.deprecated = Some(replacement);
+                },
+                _ => return Err(location_error(location, &format!("unknown option key '{}'", key)))
+            }
+        }
+        if option_spec.name.is_empty() {
+            return Err(location_error(location, "option has no id"));
+        }
+        Ok(option_spec)
+    }
+
+    // Method to add the option to a command line parser
+    // calling the add_* method of its type, it returns the option identifier
+    // * `clp` - command line parser where the option is added
+    fn add_to(&self, clp: &mut CommandLineParser) -> IOResult<u64> {
+        let to_spec_error = |location: &str| {
+            let location = location.to_string();
+            move |error: CommandLineParserError| location_error(&location, &error.to_string())
+        };
+        let short = self.short_form_option;
+        let long = self.long_form_option.as_str();
+        let (mandatory, arg_text, help_text) = (self.mandatory, self.arg_text.as_str(), self.help_text.as_str());
+        if short.is_none() && long.is_empty() &&
+            ![HELP_SPEC_TYPE, HELP_ALL_SPEC_TYPE, VERSION_SPEC_TYPE].contains(&self.typ.as_str()) {
+            return Err(location_error(&self.location, &format!("option '{}' has neither short nor long flag", self.name)));
+        }
+        let added = match (self.typ.as_str(), short) {
+            (BOOLEAN_SPEC_TYPE, Some(short)) if long.is_empty() => clp.add_short_boolean_option(short, mandatory, help_text),
+            (BOOLEAN_SPEC_TYPE, Some(short)) => clp.add_boolean_option(short, long, mandatory, help_text),
+            (BOOLEAN_SPEC_TYPE, None) => clp.add_long_boolean_option(long, mandatory, help_text),
+            (INTEGER_SPEC_TYPE, Some(short)) if long.is_empty() => clp.add_short_integer_option(short, mandatory, arg_text, help_text),
+            (INTEGER_SPEC_TYPE, Some(short)) => clp.add_integer_option(short, long, mandatory, arg_text, help_text),
+            (INTEGER_SPEC_TYPE, None) => clp.add_long_integer_option(long, mandatory, arg_text, help_text),
+            (FPOINT_SPEC_TYPE, Some(short)) if long.is_empty() => clp.add_short_fpoint_option(short, mandatory, arg_text, help_text),
+            (FPOINT_SPEC_TYPE, Some(short)) => clp.add_fpoint_option(short, long, mandatory, arg_text, help_text),
+            (FPOINT_SPEC_TYPE, None) => clp.add_long_fpoint_option(long, mandatory, arg_text, help_text),
+            (STRING_SPEC_TYPE, Some(short)) if long.is_empty() => clp.add_short_string_option(short, mandatory, arg_text, help_text),
+            (STRING_SPEC_TYPE, Some(short)) => clp.add_string_option(short, long, mandatory, arg_text, help_text),
+            (STRING_SPEC_TYPE, None) => clp.add_long_string_option(long, mandatory, arg_text, help_text),
+            (HELP_SPEC_TYPE, _) => clp.add_help_option(help_text),
+            (HELP_ALL_SPEC_TYPE, _) => clp.add_help_all_option(help_text),
+            (VERSION_SPEC_TYPE, _) => clp.add_version_option(help_text),
+            ("", _) => return Err(location_error(&self.location, &format!("option '{}' has no type", self.name))),
+            (typ, _) => return Err(location_error(&self.location, &format!("option '{}' has unknown type '{}'", self.name, typ)))
+        };
+        let option_hash = added.map_err(to_spec_error(&self.location))?;
+        if !self.long_help.is_empty() {
+            clp.set_option_long_help(&option_hash, &self.long_help).map_err(to_spec_error(&self.location))?;
+        }
+        if !self.category.is_empty() {
+            clp.set_option_category(&option_hash, &self.category).map_err(to_spec_error(&self.location))?;
+        }
+        clp.set_option_hidden(&option_hash, self.hidden).map_err(to_spec_error(&self.location))?;
+        clp.set_option_secret(&option_hash, self.secret).map_err(to_spec_error(&self.location))?;
+        if let Some(replacement) = &self.deprecated {
+            clp.set_option_deprecated(&option_hash, replacement).map_err(to_spec_error(&self.location))?;
+        }
+        clp.set_option_optional_argument(&option_hash, self.optional_argument).map_err(to_spec_error(&self.location))?;
+        if let Some(scope) = self.scope {
+            clp.set_option_scope(&option_hash, scope).map_err(to_spec_error(&self.location))?;
+        }
+        clp.set_option_section_marker(&option_hash, self.section_marker).map_err(to_spec_error(&self.location))?;
+        for (location, alias) in &self.short_aliases {
+            clp.add_short_alias(&option_hash, *alias).map_err(to_spec_error(location))?;
+        }
+        for (location, alias) in &self.long_aliases {
+            clp.add_long_alias(&option_hash, alias).map_err(to_spec_error(location))?;
+        }
+        Ok(option_hash)
+    }
+}
+
+impl CommandLineParser {
+    /// Associated function to create a command line parser from
+    /// a declarative specification text, it returns the parser and
+    /// a map from option name to the option identifier returned by the add_* methods.
+    /// The specification is made of sections, each one followed by key = value lines,
+    /// empty lines and lines starting with # are ignored, values can use
+    /// the \n and \t escape sequences:
+    /// * `[program]` - keys: name, version, about, usage, epilog, parsing_mode
+    ///   (default, positional or posix)
+    /// * `[option <name>]` - keys: type (boolean, integer, fpoint, string, help, help_all
+    ///   or version), short, long, mandatory, arg_text, help, long_help, category,
+    ///   hidden, secret, deprecated (replacement text), optional_argument, scope (global,
+    ///   next or previous, see set_option_scope), section_marker, short_aliases and
+    ///   long_aliases (comma separated lists)
+    ///
+    /// An InvalidData error with the specification line number is returned
+    /// if the specification is not valid or an add_* method fails
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let (clp, options) = CommandLineParser::from_spec("\
+    /// [program]
+    /// name = app
+    ///
+    /// [option config]
+    /// type = string
+    /// short = c
+    /// long = config
+    /// arg_text = file path
+    /// help = configuration file path
+    /// ").unwrap();
+    ///     let result = clp.parse(&["app".to_string(), "-c".to_string(), "app.properties".to_string()]).unwrap();
+    ///     assert_eq!("app.properties", result.get_value::<String>(&options["config"]).unwrap());
+    /// }
+    /// ```
+    pub fn from_spec(spec: &str) -> IOResult<(Self, HashMap<String, u64>)> {
+        let mut clp = CommandLineParser::new(None);
+        let mut options: HashMap<String, u64> = HashMap::new();
+        let mut in_program = false;
+        let mut option_spec: Option<OptionSpec> = None;
+        for (line_idx, line) in spec.lines().enumerate() {
+            let line_number = line_idx + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']')
+                                .ok_or_else(|| spec_error(line_number, &format!("missing ] in '{}'", line)))?.trim();
+                if let Some(option_spec) = option_spec.take() {
+                    let option_hash = option_spec.add_to(&mut clp)?;
+                    options.insert(option_spec.name, option_hash);
+                }
+                in_program = header == PROGRAM_SECTION;
+                if in_program {
+                    continue;
+                }
+                let name = match header.split_once(char::is_whitespace) {
+                    Some((OPTION_SECTION, name)) if !name.trim().is_empty() => name.trim(),
+                    _ => return Err(spec_error(line_number, &format!("unknown section '{}'", header)))
+                };
+                if options.contains_key(name) {
+                    return Err(spec_error(line_number, &format!("option '{}' already defined", name)));
+                }
+                option_spec = Some(OptionSpec { name: name.to_string(), location: line_location(line_number), ..Default::default() });
+                continue;
+            }
+            let (key, value) = line.split_once('=')
+                                .ok_or_else(|| spec_error(line_number, &format!("missing = in '{}'", line)))?;
+            let (key, value) = (key.trim(), value.trim());
+            if let Some(option_spec) = option_spec.as_mut() {
+                option_spec.set(line_number, key, value)?;
+            } else if in_program {
+                match key {
+                    "name" => clp.set_program_name(&unescape(value)),
+                    "version" => clp.set_version(&unescape(value)),
+                    "about" => clp.set_about(&unescape(value)),
+                    "usage" => clp.set_usage(&unescape(value)),
+                    "epilog" => clp.set_epilog(&unescape(value)),
+                    "parsing_mode" => match value {
+                        DEFAULT_SPEC_PARSING_MODE => clp.set_parsing_mode(ParsingMode::DefaultParsingMode),
+                        POSITIONAL_SPEC_PARSING_MODE => clp.set_parsing_mode(ParsingMode::PositionalArgumentsMode),
+                        POSIX_SPEC_PARSING_MODE => clp.set_parsing_mode(ParsingMode::PosixParsingMode),
+                        _ => return Err(spec_error(line_number, &format!("unknown parsing mode '{}'", value)))
+                    },
+                    _ => return Err(spec_error(line_number, &format!("unknown program key '{}'", key)))
+                }
+            } else {
+                return Err(spec_error(line_number, &format!("'{}' is outside of a section", line)));
+            }
+        }
+        if let Some(option_spec) = option_spec {
+            let option_hash = option_spec.add_to(&mut clp)?;
+            options.insert(option_spec.name, option_hash);
+        }
+        Ok((clp, options))
+    }
+
+    /// Associated function to create a command line parser
+    /// reading a declarative specification file (see from_spec)
+    /// * `path` - specification file path
+    pub fn from_spec_file<P: AsRef<Path>>(path: P) -> IOResult<(Self, HashMap<String, u64>)> {
+        Self::from_spec(&std::fs::read_to_string(path)?)
+    }
+
+    /// Associated function to create a command line parser from
+    /// a JSON specification with the schema written by to_json, so a definition
+    /// exported by to_json is read back as it is; it returns the parser and
+    /// a map from option id (i.g. -c/--config) to the option identifier returned
+    /// by the add_* methods. Members can be omitted, schema_version has to be
+    /// JSON_SCHEMA_VERSION and usage is set only if it differs from the
+    /// built one (see get_usage_text). Options of type help, help_all and version
+    /// are added by add_help_option, add_help_all_option and add_version_option.
+    /// An InvalidData error with the line number (JSON syntax) or the location
+    /// of the value (i.g. options[1]) is returned if the specification is not valid
+    /// or an add_* method fails
+    /// # Examples
+    /// ```
+    /// use rsclp::CommandLineParser;
+    ///
+    /// fn main() {
+    ///     let mut clp = CommandLineParser::new(None);
+    ///     let _ = clp.add_string_option('c', "config", true, "file path", "configuration file path").unwrap();
+    ///     let (json_clp, options) = CommandLineParser::from_json(&clp.to_json()).unwrap();
+    ///     assert_eq!(clp.to_json(), json_clp.to_json());
+    ///     let result = json_clp.parse(&["app".to_string(), "-c".to_string(), "app.properties".to_string()]).unwrap();
+    ///     assert_eq!("app.properties", result.get_value::<String>(&options["-c/--config"]).unwrap());
+    /// }
+    /// ```
+    pub fn from_json(json: &str) -> IOResult<(Self, HashMap<String, u64>)> {
+        let root = json::parse(json).map_err(|(line, message)| spec_error(line, &message))?;
+        let mut clp = CommandLineParser::new(None);
+        let mut options: HashMap<String, u64> = HashMap::new();
+        let mut usage: Option<&str> = None;
+        for (key, value) in json_members("line 1", &root)? {
+            let key = key.as_str();
+            match key {
+                "schema_version" => match value {
+                    JsonValue::Number(JSON_SCHEMA_VERSION) => {},
+                    _ => return Err(location_error(key, "unsupported schema version"))
+                },
+                "program_name" => clp.set_program_name(json_string(key, key, value)?),
+                "version" => clp.set_version(json_string(key, key, value)?),
+                "about" => clp.set_about(json_string(key, key, value)?),
+                "usage" => usage = Some(json_string(key, key, value)?),
+                "epilog" => clp.set_epilog(json_string(key, key, value)?),
+                "parsing_mode" => {
+                    let parsing_mode = json_string(key, key, value)?;
+                    clp.set_parsing_mode([ParsingMode::DefaultParsingMode, ParsingMode::PositionalArgumentsMode, ParsingMode::PosixParsingMode]
+                        .into_iter()
+                        .find(|mode| mode.to_string() == parsing_mode)
+                        .ok_or_else(|| location_error(key, &format!("unknown parsing mode '{}'", parsing_mode)))?);
+                },
+                "examples" => {
+                    for (example_idx, example) in json_array(key, key, value)?.iter().enumerate() {
+                        let location = format!("examples[{}]", example_idx);
+                        let (mut command, mut description) = ("", "");
+                        for (key, value) in json_members(&location, example)? {
+                            match key.as_str() {
+                                "command" => command = json_string(&location, key, value)?,
+                                "description" => description = json_string(&location, key, value)?,
+                                _ => return Err(location_error(&location, &format!("unknown example key '{}'", key)))
+                            }
+                        }
+                        clp.add_example(command, description);
+                    }
+                },
+                "options" => {
+                    for (option_idx, option) in json_array(key, key, value)?.iter().enumerate() {
+                        let location = format!("options[{}]", option_idx);
+                        let option_spec = OptionSpec::from_json(&location, option)?;
+                        if options.contains_key(&option_spec.name) {
+                            return Err(location_error(&location, &format!("option '{}' already defined", option_spec.name)));
+                        }
+                        let option_hash = option_spec.add_to(&mut clp)?;
+                        options.insert(option_spec.name, option_hash);
+                    }
+                },
+                _ => return Err(location_error(key, &format!("unknown program key '{}'", key)))
+            }
+        }
+        if let Some(usage) = usage.filter(|usage| *usage != clp.get_usage_text()) {
+            clp.set_usage(usage);
+        }
+        Ok((clp, options))
+    }
+
+    /// Associated function to create a command line parser
+    /// reading a JSON specification file (see from_json)
+    /// * `path` - specification file path
+    pub fn from_json_file<P: AsRef<Path>>(path: P) -> IOResult<(Self, HashMap<String, u64>)> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parser_from_spec() {
+        let (clp, options) = CommandLineParser::from_spec("\
+# application options
+[program]
+name = app
+version = app 1.0
+
+[option help]
+type = help
+help = print-out help menu
+
+[option verbose]
+type = integer
+short = V
+long = verbose
+arg_text = level
+help = set log verbosity level
+long_aliases = verbosity, log-level
+category = Logging
+
+[option dry_run]
+type = boolean
+long = dry-run
+help = do not write files
+deprecated = --check\tor -n
+
+[option input]
+type = string
+short = i
+arg_text = file
+help = input file
+section_marker = true
+
+[option seek]
+type = integer
+long = ss
+arg_text = seconds
+help = input start position
+scope = previous
+").unwrap();
+        assert_eq!(5, options.len());
+        assert_eq!("app [OPTIONS]", clp.get_usage_text());
+        let args = vec!["app".to_string(), "--log-level".to_string(), "3".to_string(), "--dry-run".to_string()];
+        let result = clp.parse(&args).unwrap();
+        assert_eq!(3, result.get_value::<i32>(&options["verbose"]).unwrap());
+        assert!(result.get_value::<bool>(&options["dry_run"]).unwrap());
+        assert_eq!(&vec!["--dry-run: option is deprecated, use --check\tor -n".to_string()], result.get_warnings());
+        assert!(!result.is_set(&options["help"]));
+        let args: Vec<String> = ["app", "-i", "a.mp4", "--ss", "10"].iter().map(|arg| arg.to_string()).collect();
+        let sections = clp.parse(&args).unwrap().get_sections();
+        assert_eq!(("a.mp4", Some(10)), (sections[0].get_anchor(), sections[0].get_value::<i64>(&options["seek"])));
+    }
+
+    #[test]
+    fn parser_from_json() {
+        let mut clp = CommandLineParser::new(None);
+        let verbose_option = clp.add_integer_option('v', "verbose", true, "level", "set \"log\" verbosity").unwrap();
+        let color_option = clp.add_long_string_option("color", false, "when", "colorize output").unwrap();
+        let input_option = clp.add_short_string_option('i', false, "file", "input file").unwrap();
+        let _ = clp.add_long_boolean_option("dry-run", false, "do not write files").unwrap();
+        clp.add_long_alias(&verbose_option, "log-level").unwrap();
+        clp.set_option_deprecated(&verbose_option, "").unwrap();
+        clp.set_option_long_help(&color_option, "Colorize output.\n\nWhen is always, auto or never.").unwrap();
+        clp.set_option_category(&color_option, "Output").unwrap();
+        clp.set_option_optional_argument(&color_option, true).unwrap();
+        clp.set_option_section_marker(&input_option, true).unwrap();
+        clp.set_option_hidden(&input_option, true).unwrap();
+        clp.set_program_name("app");
+        clp.set_version("app 1.0");
+        clp.set_about("Application.");
+        clp.add_example("app -v 2", "verbose run");
+        clp.set_parsing_mode(ParsingMode::PosixParsingMode);
+        let (json_clp, options) = CommandLineParser::from_json(&clp.to_json()).unwrap();
+        assert_eq!(clp.to_json(), json_clp.to_json());
+        assert_eq!(clp.get_help_all_text(), json_clp.get_help_all_text());
+        let args: Vec<String> = ["app", "--log-level", "2", "--color", "-i", "a", "b"].iter().map(|arg| arg.to_string()).collect();
+        let result = json_clp.parse(&args).unwrap();
+        assert_eq!(2, result.get_value::<i32>(&options["-v/--verbose"]).unwrap());
+        assert_eq!("", result.get_value::<String>(&options["--color"]).unwrap());
+        assert_eq!(&vec!["b".to_string()], result.get_positional_args());
+
+        clp.set_usage("app [OPTIONS] <file>");
+        assert_eq!("app [OPTIONS] <file>", CommandLineParser::from_json(&clp.to_json()).unwrap().0.get_usage_text());
+        let (json_clp, options) = CommandLineParser::from_json("{\"options\": [{\"id\": \"x\", \"short\": \"x\", \"type\": \"BooleanCommandLineOptionType\"}]}").unwrap();
+        assert!(json_clp.parse(&["app".to_string(), "-x".to_string()]).unwrap().is_set(&options["x"]));
+    }
+
+    #[test]
+    fn help_options_from_json() {
+        let mut clp = CommandLineParser::new(None);
+        let _ = clp.add_help_option("print-out help menu").unwrap();
+        let _ = clp.add_help_all_option("print-out help menu with hidden options").unwrap();
+        let _ = clp.add_version_option("print-out application version").unwrap();
+        let json = clp.to_json();
+        assert!(json.contains("\"type\": \"help\"") && json.contains("\"type\": \"help_all\"") && json.contains("\"type\": \"version\""));
+        let (json_clp, options) = CommandLineParser::from_json(&json).unwrap();
+        assert_eq!(json, json_clp.to_json());
+        assert_eq!((Some(options["-h/--help"]), Some(options["--help-all"]), Some(options["-v/--version"])),
+                   (json_clp.help_option, json_clp.help_all_option, json_clp.version_option));
+        assert!(json_clp.parse(&["app".to_string(), "--help".to_string()]).unwrap().is_long_help_requested());
+    }
+
+    #[test]
+    fn json_spec_errors() {
+        let error_of = |json: &str| CommandLineParser::from_json(json).err().unwrap().to_string();
+        assert_eq!("line 2: expected ',' instead of '\"'", error_of("{\"version\": \"1\"\n\"about\": \"\"}"));
+        assert_eq!("schema_version: unsupported schema version", error_of("{\"schema_version\": 99}"));
+        assert_eq!("schema_version: unsupported schema version", error_of("{\"schema_version\": 0}"));
+        assert_eq!("line 1: value is not an object", error_of("[]"));
+        assert_eq!("options[0]: unknown type 'bool'", error_of("{\"options\": [{\"id\": \"a\", \"type\": \"bool\"}]}"));
+        assert_eq!("options[0]: option has no id", error_of("{\"options\": [{\"short\": \"a\"}]}"));
+        assert_eq!("options[1]: BooleanCommandLineOptionType -a: option already exists",
+                   error_of("{\"options\": [{\"id\": \"a\", \"short\": \"a\", \"type\": \"BooleanCommandLineOptionType\"},
+                                             {\"id\": \"b\", \"short\": \"a\", \"type\": \"BooleanCommandLineOptionType\"}]}"));
+        assert_eq!("parsing_mode: unknown parsing mode 'Strict'", error_of("{\"parsing_mode\": \"Strict\"}"));
+    }
+
+    #[test]
+    fn spec_errors() {
+        let error_of = |spec: &str| CommandLineParser::from_spec(spec).err().unwrap().to_string();
+        assert_eq!("line 2: unknown option key 'shrt'", error_of("[option a]\nshrt = a\ntype = boolean"));
+        assert_eq!("line 1: option 'a' has unknown type 'bool'", error_of("[option a]\nshort = a\ntype = bool"));
+        assert_eq!("line 3: 'yes' value of mandatory is not true or false",
+                   error_of("[option a]\nshort = a\nmandatory = yes"));
+        assert_eq!("line 4: BooleanCommandLineOptionType -a: option already exists",
+                   error_of("[option a]\nshort = a\ntype = boolean\n[option b]\nshort = a\ntype = boolean"));
+        assert_eq!("line 1: 'name = app' is outside of a section", error_of("name = app"));
+        assert_eq!("line 1: unknown section 'options'", error_of("[options]"));
+        assert_eq!("line 2: unknown scope 'after'", error_of("[option a]\nscope = after"));
+    }
+}
@@ -0,0 +1,145 @@
+// Text utilities used to format the help text:
+// display width of a text in terminal columns, word wrapping
+// and ANSI escape sequences coloring
+
+// ANSI escape sequences used to color the output
+pub(crate) const ANSI_FLAGS_STYLE: &str = "\x1b[1;32m";
+pub(crate) const ANSI_ARG_TEXT_STYLE: &str = "\x1b[36m";
+pub(crate) const ANSI_HEADING_STYLE: &str = "\x1b[1;4m";
+pub(crate) const ANSI_ERROR_STYLE: &str = "\x1b[1;31m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+// Zero width characters ranges (combining marks, zero width spaces and joiners)
+const ZERO_WIDTH_RANGES: [(u32, u32); 9] = [
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x20D0, 0x20FF),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+];
+
+// East Asian wide and fullwidth characters ranges
+const WIDE_RANGES: [(u32, u32); 12] = [
+    (0x1100, 0x115F),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE30, 0xFE4F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x1F300, 0x1F64F),
+];
+
+// Supplementary ideographic planes are wide too
+const WIDE_PLANES_RANGE: (u32, u32) = (0x20000, 0x3FFFD);
+
+// Function that returns the number of terminal columns
+// used to show a character
+// * `character` - character to measure
+fn char_width(character: char) -> usize {
+    let code = character as u32;
+    if character.is_control() || ZERO_WIDTH_RANGES.iter().any(|(first, last)| (*first..=*last).contains(&code)) {
+        return 0;
+    }
+    if WIDE_RANGES.iter().any(|(first, last)| (*first..=*last).contains(&code)) ||
+        (WIDE_PLANES_RANGE.0..=WIDE_PLANES_RANGE.1).contains(&code) {
+        return 2;
+    }
+    1
+}
+
+// Function that returns the number of terminal columns
+// used to show a text
+// * `text` - text to measure
+pub(crate) fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+// Function to wrap a text in lines not wider than the passed width,
+// lines are broken between words, a word wider than the width is broken
+// between characters. 0 width means no wrapping
+// * `text` - text to wrap
+// * `width` - maximum line width in terminal columns
+pub(crate) fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if 0 == width {
+        return vec![text.to_string()];
+    }
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    let mut line_width = 0usize;
+    for word in text.split_whitespace() {
+        let word_width = display_width(word);
+        if 0 != line_width && line_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if 0 != line_width {
+            line.push(' ');
+            line_width += 1;
+        }
+        for character in word.chars() {
+            let width_of_char = char_width(character);
+            if 0 != line_width && line_width + width_of_char > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push(character);
+            line_width += width_of_char;
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+// Function that returns the passed text surrounded by
+// the ANSI escape sequences of the passed style if enabled,
+// the text as is otherwise
+// * `text` - text to color
+// * `style` - ANSI escape sequence of the style
+// * `enabled` - false to leave the text as is
+pub(crate) fn paint(text: &str, style: &str, enabled: bool) -> String {
+    if enabled && !text.is_empty() {
+        format!("{}{}{}", style, text, ANSI_RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_display_width() {
+        assert_eq!(5, display_width("hello"));
+        assert_eq!(4, display_width("日本"));
+        assert_eq!(5, display_width("caffe\u{0300}"));
+    }
+
+    #[test]
+    fn wrap_words() {
+        assert_eq!(vec!["the quick".to_string(), "brown fox".to_string(), "jumps".to_string()],
+                   wrap_text("the quick brown fox jumps", 10));
+        assert_eq!(vec!["abcd".to_string(), "efgh".to_string(), "ij".to_string()],
+                   wrap_text("abcdefghij", 4));
+        assert_eq!(vec!["日本語の".to_string(), "説明".to_string()],
+                   wrap_text("日本語の説明", 8));
+        assert_eq!(vec!["no wrap".to_string()], wrap_text("no wrap", 0));
+    }
+
+    #[test]
+    fn paint_text() {
+        assert_eq!("\x1b[1;31merror\x1b[0m", paint("error", ANSI_ERROR_STYLE, true));
+        assert_eq!("error", paint("error", ANSI_ERROR_STYLE, false));
+        assert_eq!("", paint("", ANSI_ERROR_STYLE, true));
+    }
+}